@@ -11,8 +11,22 @@ pub fn pitch(record: &PitchRecord) -> String {
         PitchOutcome::Strike(false) => "Called strike",
         PitchOutcome::Ball => "Ball",
         PitchOutcome::Foul => "Fouled off",
+        PitchOutcome::HitByPitch => "Hit by pitch",
+        PitchOutcome::WildPitch => "Wild pitch",
+        PitchOutcome::PassedBall => "Passed ball",
+        PitchOutcome::CatcherInterference => "Catcher's interference",
 
         PitchOutcome::Hit(_) => "Swung on",
+        PitchOutcome::Bunt => "Bunted",
+    };
+
+    let pitch_type = match record.pitch_type {
+        PitchType::Fastball => "fastball",
+        PitchType::Sinker => "sinker",
+        PitchType::Cutter => "cutter",
+        PitchType::Slider => "slider",
+        PitchType::Curveball => "curveball",
+        PitchType::Changeup => "changeup",
     };
 
     let location = match (&record.location.height, &record.location.width) {
@@ -28,8 +42,9 @@ pub fn pitch(record: &PitchRecord) -> String {
     };
 
     format!(
-        "{}, {}",
+        "{}, {} {}",
         call_type,
+        pitch_type,
         location,
     )
 }
@@ -38,7 +53,15 @@ pub fn at_bat_outcome(outcome: &AtBatOutcomeType) -> String {
     match outcome {
         AtBatOutcomeType::Hit(record) => hit_record(record),
         AtBatOutcomeType::Walk => format!("Walk"),
+        AtBatOutcomeType::IntentionalWalk => "Intentional walk".to_string(),
+        AtBatOutcomeType::CatcherInterference => "Catcher's interference".to_string(),
+        AtBatOutcomeType::DroppedThirdStrike(record) if record.reached_base => {
+            "Strikeout, safe on a dropped third strike".to_string()
+        }
+        AtBatOutcomeType::DroppedThirdStrike(_) => "Strikeout, thrown out at first".to_string(),
+        AtBatOutcomeType::HitByPitch => "Hit by pitch".to_string(),
         AtBatOutcomeType::Out => format!("Strikeout"),
+        AtBatOutcomeType::Unfinished => "Walk-off".to_string(),
     }
 }
 
@@ -58,7 +81,22 @@ pub fn play_result(record: &FieldingRecord) -> String {
     let landing_description = landing_description(&record.landing);
     let baserunning_description = baserunning(&record.base_running_record);
 
-    format!("{}. {}", landing_description, baserunning_description)
+    match &record.error {
+        Some(error) => format!(
+            "{}. {}. {}",
+            landing_description,
+            fielding_error(error),
+            baserunning_description
+        ),
+        None => format!("{}. {}", landing_description, baserunning_description),
+    }
+}
+
+fn fielding_error(error: &FieldingError) -> String {
+    match error.error_type {
+        ErrorType::Fielding => format!("Error on the {}", error.fielder),
+        ErrorType::Throwing => format!("Throwing error by the {}", error.fielder),
+    }
 }
 
 fn landing_description(landing: &BallLanding) -> String {
@@ -86,6 +124,8 @@ fn baserunning(record: &BaseRunningRecord) -> String {
         HitType::Double => format!("Double"),
         HitType::Triple => format!("Triple"),
         HitType::HomeRun => format!("Inside the Park Home Run"),
+        HitType::Sacrifice => "Sacrifice".to_string(),
+        HitType::ReachedOnError => "Reached on error".to_string(),
     };
 
     format!(
@@ -97,7 +137,9 @@ fn baserunning(record: &BaseRunningRecord) -> String {
 }
 
 fn field_direction(direction: &HitDirection, is_infield: bool) -> String {
-    if is_infield {
+    if direction.is_foul() {
+        "foul territory".to_string()
+    } else if is_infield {
         let direction_index = (direction.0 / 18.0) as u8;
         match direction_index {
             0 => format!("third base"),
@@ -1,5 +1,3 @@
-use rand::prelude::*;
-use rand_distr::Normal;
 use std::{io::Write, sync::mpsc::Sender, time::Duration};
 
 use crossterm::event::{self, Event, KeyCode};
@@ -16,7 +14,7 @@ fn main() -> std::io::Result<()> {
     let (mut sender, join_handle) = display::Display::start(std::io::stdout());
 
     loop {
-        let game = replaceball_sim::simulate_game(&mut RandomDecider::new());
+        let game = replaceball_sim::simulate_game(&mut SeededDecider::new(rand::random()));
         if let Err(e) = sim_game(&game, &mut sender) {
             write!(std::io::stderr(), "Error while displaying game: {}", e)?;
         }
@@ -72,9 +70,14 @@ fn sim_game(
             event_list: RingBuffer::new(20),
         },
     };
+    game_display_model
+        .event_record
+        .event_list
+        .push_back(record.conditions.to_string());
     for (game_record, _game_outcome) in record.innings.iter() {
         escapable_wait!(Duration::from_secs(2));
 
+        game_display_model.at_bat.base_state = game_record.away.starting_bases.map(|base| base.is_some());
         for (at_bat_record, inning_progress) in game_record.away.at_bats.iter() {
             update_event(
                 &mut game_display_model,
@@ -147,8 +150,11 @@ fn sim_game(
         game_display_model.at_bat.strikes = 0;
         game_display_model.at_bat.balls = 0;
         game_display_model.at_bat.outs = 0;
-        game_display_model.at_bat.base_state = [false, false, false];
-        for (at_bat_record, inning_progress) in game_record.home.at_bats.iter() {
+        game_display_model.at_bat.base_state = game_record
+            .home
+            .as_ref()
+            .map_or([false, false, false], |home| home.starting_bases.map(|base| base.is_some()));
+        for (at_bat_record, inning_progress) in game_record.home.iter().flat_map(|home| home.at_bats.iter()) {
             update_event(
                 &mut game_display_model,
                 display_sender,
@@ -237,87 +243,6 @@ fn poll_esc(duration: Duration) -> std::io::Result<bool> {
     }
 }
 
-struct RandomDecider {
-    rand: ThreadRng,
-}
-
-impl RandomDecider {
-    fn new() -> Self {
-        Self { rand: thread_rng() }
-    }
-}
-
-impl Decider for RandomDecider {
-    fn roll(&mut self, check: u64, count: u64, adjust: u64) -> bool {
-        let probability = check + adjust;
-        let roll = self.rand.gen_range(0..count);
-
-        roll < probability
-    }
-
-    fn roll_pitch_location(
-        &mut self,
-        pitch_height_bias: i8,
-        pitch_width_bias: i8,
-    ) -> PitchLocation {
-        let zone_count = 3;
-        let zone_size = -(std::i8::MIN as i16);
-        let full_range = zone_count * zone_size;
-
-        let width = match (self.rand.gen_range(0..full_range as u64)
-            .saturating_add_signed(pitch_width_bias as i64) as f32
-            / zone_size as f32) as u8
-        {
-            0 => PitchWidth::Left,
-            1 => PitchWidth::Center,
-            2 | 3 => PitchWidth::Right,
-            i => unreachable!("Value: {}", i),
-        };
-
-        let height = match (self.rand.gen_range(0..full_range as u64)
-            .saturating_add_signed(pitch_height_bias as i64) as f32
-            / zone_size as f32) as u8
-        {
-            0 => PitchHeight::High,
-            1 => PitchHeight::Middle,
-            2 | 3 => PitchHeight::Low,
-            i => unreachable!("Value: {}", i),
-        };
-
-        PitchLocation { width, height }
-    }
-
-    fn roll_index(&mut self, range: std::ops::Range<usize>) -> usize {
-        self.rand.gen_range(range)
-    }
-
-    fn roll_stat(&mut self, stat: Stat, skill: Skill) -> f64 {
-        let distr = Normal::new(
-            stat.average * skill.average_multiplier + skill.average_shift,
-            stat.std_dev * skill.std_dev_multiplier,
-        )
-        .expect("Failed to create normal distribution");
-
-        let sample = distr.sample(&mut self.rand);
-
-        if sample < stat.range.0 {
-            stat.range.0
-        } else if stat.range.1 < sample {
-            stat.range.1
-        } else {
-            sample
-        }
-    }
-
-    fn flip(&mut self, probability: f64, bias: i8) -> bool {
-        (self.rand.gen_range(0.0..1.0) + (bias as f64 / std::i8::MAX as f64 / 4.0)) < probability
-    }
-
-    fn roll_uniform(&mut self, range: std::ops::Range<f64>) -> f64 {
-        self.rand.gen_range(range)
-    }
-}
-
 fn update_event(
     display_model: &mut DisplayModel,
     display_sender: &mut Sender<Option<DisplayModel>>,
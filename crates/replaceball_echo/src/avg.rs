@@ -2,13 +2,12 @@ use std::ops::{Add, AddAssign};
 
 use replaceball_sim::prelude::*;
 
-use crate::team::{away_team, home_team};
-
 #[derive(Default, Debug)]
 pub struct Avg {
     pub home_wins: u64,
     pub runs: u64,
     pub hits: u64,
+    pub errors: u64,
 
     pub double_plays: u64,
     pub triple_plays: u64,
@@ -33,6 +32,7 @@ impl AddAssign for Avg {
     fn add_assign(&mut self, rhs: Self) {
         self.home_wins += rhs.home_wins;
         self.hits += rhs.hits;
+        self.errors += rhs.errors;
 
         self.double_plays += rhs.double_plays;
         self.triple_plays += rhs.triple_plays;
@@ -68,6 +68,7 @@ impl Add for Avg {
             triple_plays: self.triple_plays + rhs.triple_plays,
 
             hits: self.hits + rhs.hits,
+            errors: self.errors + rhs.errors,
             strikeouts: self.strikeouts + rhs.strikeouts,
             walks: self.walks + rhs.walks,
             singles: self.singles + rhs.singles,
@@ -86,16 +87,26 @@ impl Add for Avg {
     }
 }
 
-const AWAY_SKILL_BASE: i8 = 0;
-const HOME_SKILL_BASE: i8 = 0;
-
-pub fn sim_for_averages_biased(sim_count: u64, decider: &mut impl Decider) -> Avg {
+pub fn sim_for_averages_biased(
+    sim_count: u64,
+    decider: &mut impl Decider,
+    ballpark: &Ballpark,
+    conditions: &GameConditions,
+) -> Avg {
     let mut running_totals = Avg::default();
     let progress_divisor = 100;
     let progress_mod = sim_count / progress_divisor;
     let mut no_hitters = 0;
     for count in 0..sim_count {
-        let game = replaceball_sim::simulate_game_with_teams(decider, &Team::default(), &Team::default());
+        let game = replaceball_sim::simulate_game_with_teams(
+            decider,
+            &Team::default(),
+            &Team::default(),
+            &Rules::default(),
+            ballpark,
+            conditions,
+        )
+        .unwrap();
         running_totals += count_totals(&game);
         if count % progress_mod == 0 {
             println!("Completed: {:3}%", count / progress_mod);
@@ -137,6 +148,7 @@ fn count_totals(game: &GameRecord) -> Avg {
         //runs: (game.outcome.home_score) as u64,
         //runs: (game.outcome.away_score) as u64,
         hits: 0,
+        errors: (game.outcome.home_errors + game.outcome.away_errors) as u64,
 
         double_plays: 0,
         triple_plays: 0,
@@ -162,10 +174,9 @@ fn sum_game_stats(game: &GameRecord) -> Avg {
     game.innings
         .iter()
         .map(|inning| {
-            //[&inning.0.away, &inning.0.home]
-            [&inning.0.home]
-            //[&inning.0.away]
-                .iter()
+            //std::iter::once(&inning.0.away).chain(&inning.0.home)
+            inning.0.home.iter()
+            //std::iter::once(&inning.0.away)
                 .map(|half_inning| {
                     half_inning
                         .at_bats
@@ -180,9 +191,17 @@ fn sum_game_stats(game: &GameRecord) -> Avg {
 
 fn at_bat_stats(at_bat: &AtBatRecord) -> Avg {
     let (is_out_of_park, hit_type, hit_record) = match &at_bat.outcome.outcome_type {
-        AtBatOutcomeType::Out | AtBatOutcomeType::Walk => (false, None, None),
+        AtBatOutcomeType::Out
+        | AtBatOutcomeType::Walk
+        | AtBatOutcomeType::IntentionalWalk
+        | AtBatOutcomeType::HitByPitch
+        | AtBatOutcomeType::CatcherInterference
+        | AtBatOutcomeType::DroppedThirdStrike(_)
+        | AtBatOutcomeType::Unfinished => (false, None, None),
         AtBatOutcomeType::Hit(hit_record) => match hit_record.outcome.hit_type() {
-            HitType::Out => (false, None, Some(hit_record)),
+            HitType::Out | HitType::Sacrifice | HitType::ReachedOnError => {
+                (false, None, Some(hit_record))
+            }
             hit => (
                 hit_record.outcome == HitOutcome::HomeRun,
                 Some(hit),
@@ -205,14 +224,18 @@ fn at_bat_stats(at_bat: &AtBatRecord) -> Avg {
             None => 0,
             Some(_) => 1,
         },
+        errors: 0,
         double_plays: if outs_made == 2 { 1 } else { 0 },
         triple_plays: if outs_made == 3 { 1 } else { 0 },
-        strikeouts: if at_bat.pitches.last().unwrap().1.strikes == 3 {
+        // Intentional walks are issued without a pitch
+        strikeouts: if at_bat.pitches.last().is_some_and(|(_, count)| count.strikes == 3) {
             1
         } else {
             0
         },
-        walks: if at_bat.pitches.last().unwrap().1.balls == 4 {
+        walks: if at_bat.outcome.outcome_type == AtBatOutcomeType::IntentionalWalk
+            || at_bat.pitches.last().is_some_and(|(_, count)| count.balls == 4)
+        {
             1
         } else {
             0
@@ -259,6 +282,7 @@ fn pitch_stats(pitch: &PitchRecord) -> Avg {
         home_wins: 0,
         runs: 0,
         hits: 0,
+        errors: 0,
         double_plays: 0,
         triple_plays: 0,
         strikeouts: 0,
@@ -268,10 +292,9 @@ fn pitch_stats(pitch: &PitchRecord) -> Avg {
         triples: 0,
         inside_the_park: 0,
         home_runs: 0,
-        balls: if pitch.outcome == PitchOutcome::Ball {
-            1
-        } else {
-            0
+        balls: match pitch.outcome {
+            PitchOutcome::Ball | PitchOutcome::WildPitch | PitchOutcome::PassedBall => 1,
+            _ => 0,
         },
         strikes: match pitch.outcome {
             PitchOutcome::Strike(_) => 1,
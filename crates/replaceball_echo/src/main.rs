@@ -1,8 +1,6 @@
-use std::{thread, time::Duration};
+use std::{fs::File, io::BufReader, path::PathBuf, thread, time::Duration};
 
 use clap::{Parser, ValueEnum};
-use rand::prelude::*;
-use rand_distr::Normal;
 
 use replaceball_sim::prelude::*;
 
@@ -10,7 +8,6 @@ use crate::avg::Avg;
 
 mod avg;
 mod sim;
-mod team;
 
 #[derive(Clone, Debug, ValueEnum)]
 enum Mode {
@@ -18,6 +15,8 @@ enum Mode {
     Debug,
     Avg,
     AvgTeams,
+    ParkFactors,
+    Replay,
 }
 
 #[derive(Parser, Debug)]
@@ -25,26 +24,55 @@ enum Mode {
 struct Args {
     #[arg(short, long, value_enum, default_value_t = Mode::AvgTeams)]
     mode: Mode,
+
+    /// Seed for the simulation. A random seed is used if none is given
+    #[arg(short, long)]
+    seed: Option<u64>,
+
+    /// Recorded game JSON to play back in replay mode
+    #[arg(short, long)]
+    tape: Option<PathBuf>,
+
+    /// Ballpark JSON to play averages in. The default park is used if none is given
+    #[arg(short, long)]
+    ballpark: Option<PathBuf>,
+
+    /// Game conditions JSON to play averages in. A calm day at sea level is used if none is given
+    #[arg(short, long)]
+    conditions: Option<PathBuf>,
 }
 
 fn main() {
     let args = Args::parse();
 
-    let mut decider = RandomDecider::new();
-    let game_record = replaceball_sim::simulate_game(&mut decider);
+    let seed = args.seed.unwrap_or_else(rand::random);
+    println!("Seed: {}", seed);
+
+    let ballpark: Ballpark = args
+        .ballpark
+        .as_ref()
+        .map_or_else(Ballpark::default, |path| {
+            let file = File::open(path).expect("Failed to open ballpark file");
+            serde_json::from_reader(BufReader::new(file)).expect("Failed to parse ballpark")
+        });
+    let conditions: GameConditions = args
+        .conditions
+        .as_ref()
+        .map_or_else(GameConditions::default, |path| {
+            let file = File::open(path).expect("Failed to open conditions file");
+            serde_json::from_reader(BufReader::new(file)).expect("Failed to parse conditions")
+        });
+
+    let mut decider = SeededDecider::new(seed);
 
     match args.mode {
-        Mode::Sim => {
-            let mut game_record = game_record;
-            loop {
-                sim::display_game(&game_record);
-
-                thread::sleep(Duration::from_secs(1800));
+        Mode::Sim => loop {
+            let game_record = replaceball_sim::simulate_game(&mut decider);
+            sim::display_game(&game_record);
 
-                game_record = replaceball_sim::simulate_game(&mut decider);
-            }
-        }
-        Mode::Debug => println!("{:#?}", game_record),
+            thread::sleep(Duration::from_secs(1800));
+        },
+        Mode::Debug => println!("{:#?}", replaceball_sim::simulate_game(&mut decider)),
         Mode::Avg => {
             let games_count = 10_000;
             let averages = avg::sim_for_averages(games_count, &mut decider);
@@ -53,10 +81,52 @@ fn main() {
         },
         Mode::AvgTeams => {
             let games_count = 10_000;
-            let averages = avg::sim_for_averages_biased(games_count, &mut decider);
+            let averages =
+                avg::sim_for_averages_biased(games_count, &mut decider, &ballpark, &conditions);
 
             print_averages(averages, games_count);
         },
+        Mode::ParkFactors => {
+            // Both parks start from the same seed, but the streams diverge as soon
+            // as a ball carries differently, so small factors are within noise
+            let games_count = 10_000;
+            let neutral = avg::sim_for_averages_biased(
+                games_count,
+                &mut SeededDecider::new(seed),
+                &Ballpark::default(),
+                &GameConditions::default(),
+            );
+            let park = avg::sim_for_averages_biased(
+                games_count,
+                &mut SeededDecider::new(seed),
+                &ballpark,
+                &conditions,
+            );
+
+            print_park_factors(&park, &neutral);
+        },
+        Mode::Replay => {
+            let path = args.tape.expect("Replay mode requires a --tape file");
+            let file = File::open(&path).expect("Failed to open tape file");
+            let recorded: RecordedGame = serde_json::from_reader(BufReader::new(file))
+                .expect("Failed to parse recorded game");
+
+            let mut replay = ReplayDecider::new(recorded.tape);
+            let game_record = replaceball_sim::simulate_game_with_teams(
+                &mut replay,
+                &recorded.game.home_team,
+                &recorded.game.away_team,
+                &recorded.game.rules,
+                &recorded.game.ballpark,
+                &recorded.game.conditions,
+            )
+            .expect("Recorded game has invalid rules");
+            if let Err(e) = replay.finish() {
+                eprintln!("{}", e);
+            }
+
+            println!("{:#?}", game_record);
+        },
     }
 
     fn print_averages(averages: Avg, games_count: u64) {
@@ -73,6 +143,10 @@ fn main() {
                 "Hits / Game: {}",
                 averages.hits as f64 / (games_count as f64 * 2.0)
             );
+            println!(
+                "Errors / Game: {}",
+                averages.errors as f64 / (games_count as f64 * 2.0)
+            );
             println!(
                 "Double Play / Game: {}",
                 averages.double_plays as f64 / (games_count as f64 * 2.0)
@@ -135,85 +209,18 @@ fn main() {
             );
             println!("Fouls / Pitch: {}", averages.fouls as f64 / pitches as f64);
     }
-}
-
-struct RandomDecider {
-    rand: ThreadRng,
-}
-
-impl RandomDecider {
-    fn new() -> Self {
-        Self { rand: thread_rng() }
-    }
-}
-
-impl Decider for RandomDecider {
-    fn roll(&mut self, check: u64, count: u64, adjust: u64) -> bool {
-        let probability = check + adjust;
-        let roll = self.rand.gen_range(0..count);
-
-        roll < probability
-    }
-
-    fn roll_pitch_location(
-        &mut self,
-        pitch_height_bias: i8,
-        pitch_width_bias: i8,
-    ) -> PitchLocation {
-        let zone_count = 3;
-        let zone_size = -(std::i8::MIN as i16);
-        let full_range = zone_count * zone_size;
-
-        let width = match (self.rand.gen_range(0..full_range as u64)
-            .saturating_add_signed(pitch_width_bias as i64) as f32
-            / zone_size as f32) as u8
-        {
-            0 => PitchWidth::Left,
-            1 => PitchWidth::Center,
-            2 | 3 => PitchWidth::Right,
-            i => unreachable!("Value: {}", i),
-        };
-
-        let height = match (self.rand.gen_range(0..full_range as u64)
-            .saturating_add_signed(pitch_height_bias as i64) as f32
-            / zone_size as f32) as u8
-        {
-            0 => PitchHeight::High,
-            1 => PitchHeight::Middle,
-            2 | 3 => PitchHeight::Low,
-            i => unreachable!("Value: {}", i),
-        };
-
-        PitchLocation { width, height }
-    }
-
-    fn roll_index(&mut self, range: std::ops::Range<usize>) -> usize {
-        self.rand.gen_range(range)
-    }
-
-    fn roll_stat(&mut self, stat: Stat, skill: Skill) -> f64 {
-        let distr = Normal::new(
-            stat.average * skill.average_multiplier + skill.average_shift,
-            stat.std_dev * skill.std_dev_multiplier,
-        )
-        .expect("Failed to create normal distribution");
-
-        let sample = distr.sample(&mut self.rand);
-
-        if sample < stat.range.0 {
-            stat.range.0
-        } else if stat.range.1 < sample {
-            stat.range.1
-        } else {
-            sample
-        }
-    }
-
-    fn flip(&mut self, probability: f64, bias: i8) -> bool {
-        (self.rand.gen_range(0.0..1.0) + (bias as f64 / std::i8::MAX as f64 / 4.0)) < probability
-    }
 
-    fn roll_uniform(&mut self, range: std::ops::Range<f64>) -> f64 {
-        self.rand.gen_range(range)
+    /// Per game rates in the park against the default park, where 1.0 is neutral
+    fn print_park_factors(park: &Avg, neutral: &Avg) {
+        let factor = |park: u64, neutral: u64| park as f64 / neutral as f64;
+
+        println!("Runs Park Factor: {:.3}", factor(park.runs, neutral.runs));
+        println!("Hits Park Factor: {:.3}", factor(park.hits, neutral.hits));
+        println!("Doubles Park Factor: {:.3}", factor(park.doubles, neutral.doubles));
+        println!("Triples Park Factor: {:.3}", factor(park.triples, neutral.triples));
+        println!(
+            "Home Runs Park Factor: {:.3}",
+            factor(park.home_runs, neutral.home_runs)
+        );
     }
 }
@@ -21,6 +21,7 @@ macro_rules! println_wait {
 
 pub fn display_game(record: &GameRecord) {
     println_wait!("==== Away vs Home ====");
+    println_wait!("{}", record.conditions);
 
     let mut last_progress: Option<GameProgress> = None;
     for (index, (record, progress)) in record.innings.iter().enumerate() {
@@ -37,6 +38,8 @@ pub fn display_game(record: &GameRecord) {
         
         last_progress = Some(progress.clone());
     }
+
+    println_wait!("Final ({:?})", record.outcome.end_reason);
 }
 
 fn display_game_progress(progress: &GameProgress, inning: usize) {
@@ -49,14 +52,19 @@ fn display_inning(record: &InningRecord, inning: usize, home: u16, away: u16) {
     display_half_inning(&record.away);
     display_half_inning_outcome(&record.outcome.away);
 
-    println_wait!("\nBottom {}", inning);
-    println_wait!("Away: {}\nHome: {}", away + record.outcome.away.runs_scored, home);
-    display_half_inning(&record.home);
-    display_half_inning_outcome(&record.outcome.home);
+    if let (Some(home_record), Some(home_outcome)) = (&record.home, &record.outcome.home) {
+        println_wait!("\nBottom {}", inning);
+        println_wait!("Away: {}\nHome: {}", away + record.outcome.away.runs_scored, home);
+        display_half_inning(home_record);
+        display_half_inning_outcome(home_outcome);
+    }
 }
 
 fn display_half_inning(record: &HalfInningRecord) {
-    display_half_inning_progress(&HalfInningProgress::default());
+    display_half_inning_progress(&HalfInningProgress {
+        bases: record.starting_bases.map(|base| base.is_some()),
+        ..Default::default()
+    });
     for (at_bat, progress) in record.at_bats.iter() {
         println_wait!();
         display_at_bat(at_bat);
@@ -117,10 +125,25 @@ fn display_half_inning_outcome(outcome: &HalfInningOutcome) {
         outcome.total_hits,
         if outcome.total_hits == 1 { hit_description.to_owned() } else { pluralize::to_plural(hit_description) },
     );
+    if outcome.errors > 0 {
+        println_wait!(
+            "{} {}, {} unearned",
+            outcome.errors,
+            if outcome.errors == 1 { "Error" } else { "Errors" },
+            outcome.unearned_runs,
+        );
+    }
 }
 
 fn display_at_bat(at_bat: &AtBatRecord) {
     println_wait!("Now Batting: #{}", at_bat.batter_index + 1);
+    match &at_bat.alignment {
+        DefensiveAlignment::Standard => (),
+        DefensiveAlignment::InfieldIn => println_wait!("The infield is in"),
+        DefensiveAlignment::NoDoubles => println_wait!("No doubles defense"),
+        DefensiveAlignment::PullShift { .. } => println_wait!("The shift is on"),
+        DefensiveAlignment::Custom(_) => println_wait!("Fielders in custom positions"),
+    }
 
     display_at_bat_progress(&AtBatProgress::default());
 
@@ -138,6 +161,19 @@ fn display_at_bat(at_bat: &AtBatRecord) {
         AtBatOutcomeType::Hit(ref hit_record) => display_hit(&hit_record),
         AtBatOutcomeType::Out => println_wait!("Strikeout"),
         AtBatOutcomeType::Walk => println_wait!("Walk"),
+        AtBatOutcomeType::CatcherInterference => println_wait!("Catcher's interference"),
+        AtBatOutcomeType::DroppedThirdStrike(ref record) => {
+            println_wait!("Strike three, and it gets away!");
+            display_fielding(&record.play);
+            if record.reached_base {
+                println_wait!("Safe at first");
+            } else {
+                println_wait!("Out at First");
+            }
+        }
+        AtBatOutcomeType::IntentionalWalk => println_wait!("Intentional walk"),
+        AtBatOutcomeType::HitByPitch => println_wait!("Hit by pitch"),
+        AtBatOutcomeType::Unfinished => println_wait!("That's the ballgame"),
     }
 }
 
@@ -154,6 +190,14 @@ fn display_at_bat_progress(progress: &AtBatProgress) {
 }
 
 fn display_pitch(pitch: &PitchRecord) {
+    let pitch_type = match pitch.pitch_type {
+        PitchType::Fastball => "Fastball",
+        PitchType::Sinker => "Sinker",
+        PitchType::Cutter => "Cutter",
+        PitchType::Slider => "Slider",
+        PitchType::Curveball => "Curveball",
+        PitchType::Changeup => "Changeup",
+    };
     let location = match (&pitch.location.height, &pitch.location.width) {
         (PitchHeight::High, PitchWidth::Left) => "high and outside",
         (PitchHeight::High, PitchWidth::Center) => "high",
@@ -165,14 +209,24 @@ fn display_pitch(pitch: &PitchRecord) {
         (PitchHeight::Low, PitchWidth::Center) => "low",
         (PitchHeight::Low, PitchWidth::Right) => "low and in",
     };
-    print!("Pitch is {}.", location);
+    match &pitch.location.plate {
+        Some(plate) => print!("{} is {} ({:+.1}in, {:+.1}in).", pitch_type, location, plate.x, plate.z),
+        None => print!("{} is {}.", pitch_type, location),
+    }
 
     let result = match pitch.outcome {
         PitchOutcome::Strike(false) => "Called strike.",
+        PitchOutcome::Strike(true) if pitch.batting_call.is_bunt() => "Misses the bunt.",
         PitchOutcome::Strike(true) => "Swings and misses.",
         PitchOutcome::Ball => "Ball.",
+        PitchOutcome::Foul if pitch.batting_call.is_bunt() => "Bunts it foul.",
         PitchOutcome::Foul => "Fouled off.",
+        PitchOutcome::HitByPitch => "Hits the batter.",
+        PitchOutcome::WildPitch => "Ball, wild pitch. Runners advance.",
+        PitchOutcome::PassedBall => "Ball, passed ball. Runners advance.",
+        PitchOutcome::CatcherInterference => "Swings and catches the catcher's glove.",
         PitchOutcome::Hit(_) => "Swings.",
+        PitchOutcome::Bunt => "Lays down a bunt.",
     };
     println_wait!(" {}", result);
 }
@@ -207,9 +261,11 @@ fn display_hit(record: &HitRecord) {
     };
 
     let hit_type = record.outcome.hit_type();
-    let direction_comment = if record.launch_angle.0 < 10.0 {
+    let direction_comment = if record.direction.is_foul() {
+        "into foul territory"
+    } else if record.launch_angle.0 < 10.0 {
         // Groundball
-        if hit_type == HitType::Out {
+        if matches!(hit_type, HitType::Out | HitType::ReachedOnError) {
             match record.direction.0 {
                 angle if angle < 1.0 * (90.0 / 7.0) => "to third base",
                 angle if angle < 2.0 * (90.0 / 7.0) => "to the left side of the infield",
@@ -290,8 +346,14 @@ fn display_hit(record: &HitRecord) {
                     display_fielding(fielding_play);
                 },
             };
+            if let Some(error) = &fielding_record.error {
+                display_error(error);
+            }
 
             display_baserunning(&fielding_record.base_running_record);
+            if fielding_record.base_running_record.outcome.batter_hit_type == HitType::Sacrifice {
+                println_wait!("Sacrifice");
+            }
         },
     };
 }
@@ -300,6 +362,13 @@ fn display_catch(fielder: &Fielder) {
     println_wait!("Caught by the {}", fielder);
 }
 
+fn display_error(error: &FieldingError) {
+    match error.error_type {
+        ErrorType::Fielding => println_wait!("Error on the {}!", error.fielder),
+        ErrorType::Throwing => println_wait!("The throw from the {} gets away!", error.fielder),
+    }
+}
+
 fn display_fielding(play: &FieldingPlay) {
     println_wait!("Fielded by the {}.", play.from);
     println_wait!("Over to the {}.", play.to);
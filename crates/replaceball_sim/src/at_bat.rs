@@ -3,20 +3,25 @@ use serde::{Deserialize, Serialize};
 use ts_rs::TS;
 
 use crate::{
-    hit::simulate_hit, pitch::simulate_pitch, prelude::*
+    base_running::batter_beats_throw,
+    fielding::dropped_third_strike_play,
+    hit::{simulate_bunt, simulate_foul, simulate_hit},
+    pitch::simulate_pitch,
+    prelude::*,
 };
 
-
 #[derive(Clone, Debug, TS)]
 #[ts(export)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct AtBatRecord {
     pub batter_index: u8,
+    /// Where the fielding team played for the at bat
+    pub alignment: DefensiveAlignment,
     pub pitches: Box<[(PitchRecord, AtBatProgress)]>,
     pub outcome: AtBatOutcome,
 }
 
-#[derive(Default, Clone, Debug, TS)]
+#[derive(Default, Clone, Debug, PartialEq, Eq, TS)]
 #[ts(export)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct AtBatProgress {
@@ -30,7 +35,26 @@ pub struct AtBatProgress {
 pub enum AtBatOutcomeType {
     Hit(HitRecord),
     Walk,
+    /// Put on by the fielding team without a pitch
+    IntentionalWalk,
+    HitByPitch,
+    /// Reaches on the catcher's interference with a swing
+    CatcherInterference,
+    /// Struck out, but the third strike got away and the batter ran. Still a
+    /// strikeout, but only an out if the throw beat the batter to first.
+    DroppedThirdStrike(DroppedThirdStrikeRecord),
     Out,
+    /// Cut short by the winning run scoring before the batter finished, e.g. on a wild pitch
+    Unfinished,
+}
+
+#[derive(Clone, Debug, PartialEq, TS)]
+#[ts(export)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct DroppedThirdStrikeRecord {
+    /// The catcher's throw to first
+    pub play: FieldingPlay,
+    pub reached_base: bool,
 }
 
 #[derive(Clone, Debug, TS)]
@@ -40,101 +64,346 @@ pub struct AtBatOutcome {
     pub outcome_type: AtBatOutcomeType,
 }
 
+#[allow(clippy::too_many_arguments)]
 pub fn simulate_at_bat(
     batter_index: u8,
     batting_team: &Team,
     fielding_team: &Team,
+    rules: &Rules,
+    ballpark: &Ballpark,
+    conditions: &GameConditions,
     decider: &mut impl Decider,
     base_state: &[Option<u8>; 3],
+    outs: u8,
+    inning_index: usize,
 ) -> AtBatRecord {
-    let batter = batting_team.player_at_batting_index(batter_index);
-    let pitcher = fielding_team.pitcher();
-    let mut state = AtBatState::new();
-    let mut pitches = Vec::<(PitchRecord, AtBatProgress)>::new();
+    let mut at_bat = AtBatSimulation::new(
+        batter_index,
+        rules,
+        ballpark,
+        conditions,
+        base_state,
+        outs,
+        inning_index,
+    );
+    while !at_bat.is_over() {
+        at_bat.step_pitch(batting_team, fielding_team, decider);
+    }
+
+    at_bat.into_record()
+}
 
-    while state.outcome_type().is_none() {
-        let pitch_record = simulate_pitch(decider, batter, pitcher);
+/// An at bat in progress, simulated one pitch at a time
+pub struct AtBatSimulation {
+    batter_index: u8,
+    base_state: [Option<u8>; 3],
+    outs: u8,
+    inning_index: usize,
+    ballpark: Ballpark,
+    conditions: GameConditions,
+    /// Set before the first pitch
+    alignment: Option<DefensiveAlignment>,
+    outs_per_half_inning: u8,
+    players_per_lineup: u8,
+    innings: u8,
+    state: AtBatState,
+    pitches: Vec<(PitchRecord, AtBatProgress)>,
+}
+
+impl AtBatSimulation {
+    pub fn new(
+        batter_index: u8,
+        rules: &Rules,
+        ballpark: &Ballpark,
+        conditions: &GameConditions,
+        base_state: &[Option<u8>; 3],
+        outs: u8,
+        inning_index: usize,
+    ) -> Self {
+        Self {
+            batter_index,
+            base_state: *base_state,
+            outs,
+            inning_index,
+            ballpark: *ballpark,
+            conditions: *conditions,
+            alignment: None,
+            outs_per_half_inning: rules.outs_per_half_inning,
+            players_per_lineup: rules.players_per_lineup,
+            innings: rules.innings,
+            state: AtBatState::new(rules),
+            pitches: Vec::new(),
+        }
+    }
+
+    /// Throws the next pitch, or puts the batter on without one when the
+    /// fielding team calls for an intentional walk
+    pub fn step_pitch(
+        &mut self,
+        batting_team: &Team,
+        fielding_team: &Team,
+        decider: &mut impl Decider,
+    ) -> (Option<PitchRecord>, AtBatProgress) {
+        let situation = Situation {
+            batting_team,
+            fielding_team,
+            batter_index: self.batter_index,
+            players_per_lineup: self.players_per_lineup,
+            outs_per_half_inning: self.outs_per_half_inning,
+            innings: self.innings,
+            count: self.progress(),
+            outs: self.outs,
+            base_state: self.base_state,
+            inning_index: self.inning_index,
+        };
+        let alignment = self
+            .alignment
+            .get_or_insert_with(|| decider.defensive_alignment(&situation))
+            .clone();
+        let fielding_call = decider.fielding_call(&situation);
+        if fielding_call == FieldingCall::IntentionalWalk {
+            self.state.intentional_walk();
+            return (None, self.progress());
+        }
+
+        let call = decider.batting_call(&situation);
+        let runners_going = self.runners_going(call);
+        let mut pitch_record =
+            simulate_pitch(decider, &situation, call, fielding_call, runners_going);
 
         match pitch_record.outcome {
-            PitchOutcome::Strike(_) => state.strike(),
-            PitchOutcome::Foul => state.foul(),
-            PitchOutcome::Ball => state.ball(),
-            PitchOutcome::Hit(is_ball) => handle_hit(
-                &pitch_record.location,
-                is_ball,
-                batter_index,
+            PitchOutcome::Strike(_) => {
+                self.state.strike();
+                self.check_dropped_third_strike(batting_team, fielding_team, decider);
+            }
+            // A foul bunt is a strike, even with two strikes
+            PitchOutcome::Foul if call.is_bunt() => self.state.strike(),
+            PitchOutcome::Foul => match simulate_foul(
+                &pitch_record,
+                self.batter_index,
+                batting_team,
+                fielding_team,
+                &alignment,
+                &self.ballpark,
+                &self.conditions,
+                decider,
+                &self.base_state,
+                runners_going,
+            ) {
+                // Caught short of the stands
+                Some(hit_record) => self.state.hit(hit_record),
+                None => self.state.foul(),
+            },
+            PitchOutcome::Ball => self.state.ball(),
+            PitchOutcome::HitByPitch => self.state.hit_by_pitch(),
+            PitchOutcome::CatcherInterference => self.state.catcher_interference(),
+            PitchOutcome::WildPitch | PitchOutcome::PassedBall => {
+                advance_runners(&mut self.base_state);
+                self.state.ball();
+            }
+            PitchOutcome::Hit(is_ball) => {
+                let curved_foul = handle_hit(
+                    &pitch_record,
+                    is_ball,
+                    self.batter_index,
+                    batting_team,
+                    fielding_team,
+                    &alignment,
+                    &self.ballpark,
+                    &self.conditions,
+                    decider,
+                    &mut self.state,
+                    &self.base_state,
+                    runners_going,
+                );
+                if curved_foul {
+                    pitch_record.outcome = PitchOutcome::Foul;
+                }
+            }
+            PitchOutcome::Bunt => self.state.hit(simulate_bunt(
+                &pitch_record,
+                self.batter_index,
                 batting_team,
                 fielding_team,
+                &alignment,
                 decider,
-                &mut state,
-                base_state,
-            ),
+                &self.base_state,
+                self.outs,
+                self.outs_per_half_inning,
+                runners_going,
+            )),
         }
 
-        let progress = AtBatProgress {
-            balls: state.current_balls(),
-            strikes: state.current_strikes()
-        };
+        let progress = self.progress();
+        self.pitches.push((pitch_record.clone(), progress.clone()));
 
-        pitches.push((pitch_record, progress));
+        (Some(pitch_record), progress)
     }
 
-    let outcome = AtBatOutcome {
-        outcome_type: state.outcome_type().expect("At bat is not active but no outcome found.")
-    };
+    /// Runners go with the pitch on a hit and run from first, and with two outs
+    /// and a full count wherever they are
+    pub(crate) fn runners_going(&self, call: BattingCall) -> bool {
+        (call == BattingCall::HitAndRun && self.base_state[Consts::FIRST].is_some())
+            || (self.outs + 1 == self.outs_per_half_inning && self.state.is_full_count())
+    }
 
-    AtBatRecord {
-        batter_index,
-        pitches: pitches.into_boxed_slice(),
-        outcome,
+    /// The batter can only run on a third strike with first base open or two outs
+    fn check_dropped_third_strike(
+        &mut self,
+        batting_team: &Team,
+        fielding_team: &Team,
+        decider: &mut impl Decider,
+    ) {
+        if self.state.strikes_remaining != 0
+            || (self.base_state[Consts::FIRST].is_some()
+                && self.outs + 1 < self.outs_per_half_inning)
+            || !decider.flip(
+                Draw::DroppedThirdStrike,
+                levels::DROPPED_THIRD_STRIKES_PER_STRIKEOUT,
+                fielding_team
+                    .player_at_position(&Fielder::Catcher)
+                    .catcher_blocking_bias,
+            )
+        {
+            return;
+        }
+
+        let play = dropped_third_strike_play(fielding_team, decider);
+        let reached_base = batter_beats_throw(
+            batting_team.player_at_batting_index(self.batter_index),
+            &play,
+            decider,
+        );
+        self.state
+            .dropped_third_strike(DroppedThirdStrikeRecord { play, reached_base });
+    }
+
+    pub fn progress(&self) -> AtBatProgress {
+        AtBatProgress {
+            balls: self.state.current_balls(),
+            strikes: self.state.current_strikes(),
+        }
     }
+
+    /// Runners on base, moved up by any wild pitch or passed ball so far
+    pub fn base_state(&self) -> &[Option<u8>; 3] {
+        &self.base_state
+    }
+
+    pub fn is_over(&self) -> bool {
+        self.state.is_over()
+    }
+
+    /// Ends the at bat without the batter finishing it
+    pub fn cut_short(&mut self) {
+        self.state.cut_short();
+    }
+
+    pub fn into_record(self) -> AtBatRecord {
+        let outcome = AtBatOutcome {
+            outcome_type: self
+                .state
+                .outcome_type()
+                .expect("At bat is not active but no outcome found."),
+        };
+
+        AtBatRecord {
+            batter_index: self.batter_index,
+            alignment: self.alignment.unwrap_or_default(),
+            pitches: self.pitches.into_boxed_slice(),
+            outcome,
+        }
+    }
+}
+
+/// Moves every runner up a base, the runner on third coming home
+fn advance_runners(base_state: &mut [Option<u8>; 3]) {
+    base_state[Consts::THIRD] = base_state[Consts::SECOND];
+    base_state[Consts::SECOND] = base_state[Consts::FIRST];
+    base_state[Consts::FIRST] = None;
 }
 
+/// Returns whether the ball curved foul out of reach, which only counts as a foul
+#[allow(clippy::too_many_arguments)]
 fn handle_hit(
-    pitch_location: &PitchLocation,
+    pitch: &PitchRecord,
     is_ball: bool,
     batter_lineup_index: u8,
     batting_team: &Team,
     fielding_team: &Team,
+    alignment: &DefensiveAlignment,
+    ballpark: &Ballpark,
+    conditions: &GameConditions,
     decider: &mut impl Decider,
     state: &mut AtBatState,
     base_state: &[Option<u8>; 3],
-) {
-    let hit_record = simulate_hit(
-        pitch_location,
+    runners_going: bool,
+) -> bool {
+    match simulate_hit(
+        pitch,
         is_ball,
         batter_lineup_index,
         batting_team,
         fielding_team,
+        alignment,
+        ballpark,
+        conditions,
         decider,
         base_state,
-    );
-
-    state.hit(hit_record);
+        runners_going,
+    ) {
+        Some(hit_record) => {
+            state.hit(hit_record);
+            false
+        }
+        // Curved foul out of reach
+        None => {
+            state.foul();
+            true
+        }
+    }
 }
 
 
 struct AtBatState {
+    balls_per_walk: u8,
+    strikes_per_strikeout: u8,
     balls_remaining: u8,
     strikes_remaining: u8,
     hit_record: Option<HitRecord>,
+    hit_by_pitch: bool,
+    intentional_walk: bool,
+    catcher_interference: bool,
+    dropped_third_strike: Option<DroppedThirdStrikeRecord>,
+    cut_short: bool,
 }
 
 impl AtBatState {
-    fn new() -> Self {
+    fn new(rules: &Rules) -> Self {
         Self {
-            balls_remaining: Consts::BALLS_PER_WALK,
-            strikes_remaining: Consts::STRIKES_PER_STRIKEOUT,
+            balls_per_walk: rules.balls_per_walk,
+            strikes_per_strikeout: rules.strikes_per_strikeout,
+            balls_remaining: rules.balls_per_walk,
+            strikes_remaining: rules.strikes_per_strikeout,
             hit_record: None,
+            hit_by_pitch: false,
+            intentional_walk: false,
+            catcher_interference: false,
+            dropped_third_strike: None,
+            cut_short: false,
         }
     }
 
     fn current_balls(&self) -> u8 {
-        Consts::BALLS_PER_WALK - self.balls_remaining
+        self.balls_per_walk - self.balls_remaining
     }
 
     fn current_strikes(&self) -> u8 {
-        Consts::STRIKES_PER_STRIKEOUT - self.strikes_remaining
+        self.strikes_per_strikeout - self.strikes_remaining
+    }
+
+    fn is_full_count(&self) -> bool {
+        self.balls_remaining == 1 && self.strikes_remaining == 1
     }
 
     fn ball(&mut self) {
@@ -144,7 +413,7 @@ impl AtBatState {
     fn strike(&mut self) {
         self.strikes_remaining -= 1;
     }
-    
+
     fn foul(&mut self) {
         if self.strikes_remaining != 1 {
             self.strikes_remaining -= 1;
@@ -155,13 +424,53 @@ impl AtBatState {
         self.hit_record = Some(hit_record);
     }
 
+    fn hit_by_pitch(&mut self) {
+        self.hit_by_pitch = true;
+    }
+
+    fn intentional_walk(&mut self) {
+        self.intentional_walk = true;
+    }
+
+    fn catcher_interference(&mut self) {
+        self.catcher_interference = true;
+    }
+
+    fn dropped_third_strike(&mut self, record: DroppedThirdStrikeRecord) {
+        self.dropped_third_strike = Some(record);
+    }
+
+    fn cut_short(&mut self) {
+        self.cut_short = true;
+    }
+
+    fn is_over(&self) -> bool {
+        self.hit_record.is_some()
+            || self.hit_by_pitch
+            || self.intentional_walk
+            || self.catcher_interference
+            || self.cut_short
+            || self.balls_remaining == 0
+            || self.strikes_remaining == 0
+    }
+
     fn outcome_type(&self) -> Option<AtBatOutcomeType> {
         if let Some(hit_record) = self.hit_record.clone() {
             Some(AtBatOutcomeType::Hit(hit_record))
+        } else if self.hit_by_pitch {
+            Some(AtBatOutcomeType::HitByPitch)
+        } else if self.intentional_walk {
+            Some(AtBatOutcomeType::IntentionalWalk)
+        } else if self.catcher_interference {
+            Some(AtBatOutcomeType::CatcherInterference)
+        } else if let Some(record) = self.dropped_third_strike.clone() {
+            Some(AtBatOutcomeType::DroppedThirdStrike(record))
         } else if self.balls_remaining == 0 {
             Some(AtBatOutcomeType::Walk)
         } else if self.strikes_remaining == 0 {
             Some(AtBatOutcomeType::Out)
+        } else if self.cut_short {
+            Some(AtBatOutcomeType::Unfinished)
         } else {
             None
         }
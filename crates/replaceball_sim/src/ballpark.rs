@@ -0,0 +1,112 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use ts_rs::TS;
+
+use crate::{location::Cartesian, prelude::*};
+
+/// The park a game is played in. `Default` is a symmetrical park deep to center.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, TS)]
+#[ts(export)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Ballpark {
+    /// The outfield wall at evenly spaced directions, from the left field line
+    /// around to the right field line
+    pub fences: [Fence; Ballpark::FENCE_POINTS],
+
+    /// How much room the park leaves to catch foul balls
+    pub foul_territory: FoulTerritory,
+
+    /// Feet above sea level. The ball carries further in thinner air.
+    pub altitude: u16,
+}
+
+/// The outfield wall at one point, in feet
+#[derive(Debug, Clone, Copy, PartialEq, Eq, TS)]
+#[ts(export)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Fence {
+    /// From home plate
+    pub distance: u16,
+    pub height: u16,
+}
+
+impl Ballpark {
+    pub const FENCE_POINTS: usize = 7;
+
+    /// Distance to the wall, interpolated between the nearest fence points
+    pub fn fence_distance(&self, direction: HitDirection) -> Distance {
+        Distance(self.interpolate(direction, |fence| fence.distance))
+    }
+
+    /// Height of the wall, interpolated between the nearest fence points
+    pub fn wall_height(&self, direction: HitDirection) -> f64 {
+        self.interpolate(direction, |fence| fence.height)
+    }
+
+    fn interpolate(&self, direction: HitDirection, value: impl Fn(&Fence) -> u16) -> f64 {
+        let spacing = 90.0 / (Self::FENCE_POINTS - 1) as f64;
+        let position = (direction.0 / spacing).clamp(0.0, (Self::FENCE_POINTS - 1) as f64);
+        let index = (position as usize).min(Self::FENCE_POINTS - 2);
+        let t = position - index as f64;
+
+        let from = value(&self.fences[index]) as f64;
+        let to = value(&self.fences[index + 1]) as f64;
+        from + (to - from) * t
+    }
+}
+
+impl Default for Ballpark {
+    fn default() -> Self {
+        let fence = |distance| Fence {
+            distance,
+            height: 8,
+        };
+
+        Self {
+            fences: [
+                fence(400),
+                fence(400),
+                fence(420),
+                fence(430),
+                fence(420),
+                fence(400),
+                fence(400),
+            ],
+            foul_territory: FoulTerritory::default(),
+            altitude: 0,
+        }
+    }
+}
+
+/// The foul ground between the field and the stands, in feet
+#[derive(Debug, Clone, Copy, PartialEq, Eq, TS)]
+#[ts(export)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct FoulTerritory {
+    /// From home plate back to the backstop
+    pub behind_plate: u16,
+    /// Out from either foul line to the stands
+    pub beside_lines: u16,
+}
+
+impl FoulTerritory {
+    /// Whether a ball at the location is still in the park, rather than in the stands
+    pub fn contains(&self, location: Location) -> bool {
+        let Cartesian(x, y) = location.into();
+
+        if x < 0.0 && y < 0.0 {
+            location.distance.0 <= self.behind_plate as f64
+        } else {
+            x.min(y) >= -(self.beside_lines as f64)
+        }
+    }
+}
+
+impl Default for FoulTerritory {
+    fn default() -> Self {
+        Self {
+            behind_plate: 60,
+            beside_lines: 30,
+        }
+    }
+}
@@ -31,6 +31,8 @@ pub struct BaseRunningOutcome {
     pub runs_scored: u8,
     pub batter_hit_type: HitType,
     pub ending_base_state: [Option<u8>; 3],
+    /// Runs that only scored because of an error on the play
+    pub unearned_runs: u8,
 }
 
 #[derive(Clone, PartialEq, Debug, TS)]
@@ -47,6 +49,7 @@ pub fn simulate_base_running(
     fielding_team: &Team,
     ball_landing: &BallLanding,
     base_state: &[Option<u8>; 3],
+    runners_going: bool,
     decider: &mut impl Decider,
 ) -> BaseRunningRecord {
     match ball_landing {
@@ -54,6 +57,7 @@ pub fn simulate_base_running(
             fielder,
             location,
             base_state,
+            runners_going,
             batting_team,
             fielding_team,
             decider,
@@ -63,6 +67,7 @@ pub fn simulate_base_running(
             fielding_play,
             batter_lineup_index,
             base_state,
+            runners_going,
             batting_team,
             fielding_team,
             decider,
@@ -70,10 +75,176 @@ pub fn simulate_base_running(
     }
 }
 
+/// Runners going with the pitch are already on their way at contact
+fn takeoff_delay(runner: &Player, runners_going: bool, decider: &mut impl Decider) -> f64 {
+    let delay = decider.roll_std_dev_skill_stat(
+        Draw::BaseTakeoffDelay,
+        *levels::BASE_TAKEOFF_DELAY,
+        runner.baserunner_takeoff_delay_bias,
+    );
+
+    if runners_going {
+        delay - levels::RUNNING_WITH_PITCH_HEAD_START
+    } else {
+        delay
+    }
+}
+
+/// Turns a batter thrown out at first into a sacrifice when the play moved a
+/// runner up. Only called on bunts before the last out of the half inning.
+pub(crate) fn credit_sacrifice(record: &mut BaseRunningRecord) {
+    let runner_advanced = record.movements.iter().any(|movement| {
+        movement.starting_base.is_some() && matches!(movement.bases_moved, MoveType::Advanced(_))
+    });
+
+    if record.outcome.batter_hit_type == HitType::Out
+        && record.outcome.outs_made == 1
+        && runner_advanced
+    {
+        record.outcome.batter_hit_type = HitType::Sacrifice;
+    }
+}
+
+/// Everyone safe on an error that should have made an out, the batter and
+/// each runner taking `bases`. Runs beyond those the clean play scored are unearned.
+pub(crate) fn reached_on_error(
+    batter_lineup_index: u8,
+    base_state: &[Option<u8>; 3],
+    bases: usize,
+    clean_outcome: &BaseRunningOutcome,
+) -> BaseRunningRecord {
+    let mut base_movements = Vec::<BaseMovement>::new();
+    let mut ending_base_state = [None; 3];
+    let mut runs_scored: u8 = 0;
+
+    for base in [Consts::THIRD, Consts::SECOND, Consts::FIRST] {
+        if let Some(runner) = base_state[base] {
+            base_movements.push(BaseMovement {
+                starting_base: Some(base),
+                bases_moved: MoveType::Advanced(bases.min(Consts::HOME - base)),
+            });
+            if base + bases >= Consts::HOME {
+                runs_scored += 1;
+            } else {
+                ending_base_state[base + bases] = Some(runner);
+            }
+        }
+    }
+
+    base_movements.push(BaseMovement {
+        starting_base: None,
+        bases_moved: MoveType::Advanced(bases),
+    });
+    ending_base_state[bases - 1] = Some(batter_lineup_index);
+
+    BaseRunningRecord {
+        movements: base_movements.into_boxed_slice(),
+        outcome: BaseRunningOutcome {
+            outs_made: 0,
+            runs_scored,
+            batter_hit_type: HitType::ReachedOnError,
+            ending_base_state,
+            unearned_runs: runs_scored.saturating_sub(clean_outcome.runs_scored),
+        },
+    }
+}
+
+/// Runners the clean play put out are safe where they were thrown out
+/// instead, scoring an unearned run if that was home. The batter keeps
+/// whatever the clean play gave them.
+pub(crate) fn runners_safe_on_error(
+    base_state: &[Option<u8>; 3],
+    mut record: BaseRunningRecord,
+) -> BaseRunningRecord {
+    for movement in record.movements.iter_mut() {
+        let (Some(start), MoveType::Out(base)) = (movement.starting_base, &movement.bases_moved)
+        else {
+            continue;
+        };
+        let base = *base;
+
+        movement.bases_moved = MoveType::Advanced(base - start);
+        record.outcome.outs_made -= 1;
+        if base == Consts::HOME {
+            record.outcome.runs_scored += 1;
+            record.outcome.unearned_runs += 1;
+        } else {
+            record.outcome.ending_base_state[base] = base_state[start];
+        }
+    }
+
+    record
+}
+
+/// Everyone left on base takes one more when a throw gets away, the runner
+/// on third scoring an unearned run
+pub(crate) fn extra_base_on_error(mut record: BaseRunningRecord) -> BaseRunningRecord {
+    let ending_base_state = record.outcome.ending_base_state;
+    let mut base_movements = record.movements.into_vec();
+
+    for base in [Consts::THIRD, Consts::SECOND, Consts::FIRST] {
+        if ending_base_state[base].is_none() {
+            continue;
+        }
+
+        // Runners held in place by the play have no movement yet
+        let movement = base_movements.iter_mut().find(|movement| {
+            matches!(movement.bases_moved, MoveType::Advanced(moved)
+                if movement.starting_base.map_or(0, |start| start + 1) + moved == base + 1)
+        });
+        match movement {
+            Some(BaseMovement {
+                bases_moved: MoveType::Advanced(moved),
+                ..
+            }) => *moved += 1,
+            _ => base_movements.push(BaseMovement {
+                starting_base: Some(base),
+                bases_moved: MoveType::Advanced(1),
+            }),
+        }
+    }
+
+    let scored = ending_base_state[Consts::THIRD].is_some() as u8;
+    record.movements = base_movements.into_boxed_slice();
+    record.outcome.ending_base_state = [
+        None,
+        ending_base_state[Consts::FIRST],
+        ending_base_state[Consts::SECOND],
+    ];
+    record.outcome.runs_scored += scored;
+    record.outcome.unearned_runs += scored;
+
+    record
+}
+
+/// Whether the batter gets down the line before a throw to first, as on a
+/// dropped third strike
+pub(crate) fn batter_beats_throw(
+    batter: &Player,
+    fielding_play: &FieldingPlay,
+    decider: &mut impl Decider,
+) -> bool {
+    let batter_runner_speed = decider.roll_std_dev_skill_stat(
+        Draw::BaserunnerSpeed,
+        *levels::BASERUNNER_SPEED,
+        batter.baserunner_run_speed_bias,
+    );
+    let batter_box_exit_time = decider.roll_std_dev_skill_stat(
+        Draw::BoxExitTime,
+        *levels::BOX_EXIT_TIME,
+        batter.baserunner_box_exit_time_bias,
+    );
+
+    90.0 / batter_runner_speed + batter_box_exit_time <= fielding_play.to_event.travel_time.0
+}
+
+/// `runners_going` is only set when the catch came too quickly for runners
+/// going with the pitch to read it and get back
 fn post_out_base_running(
     fielder: &Fielder,
     location: &Location,
     base_state: &[Option<u8>; 3],
+    runners_going: bool,
     batting_team: &Team,
     fielding_team: &Team,
     decider: &mut impl Decider,
@@ -83,6 +254,7 @@ fn post_out_base_running(
     let mut base_movements = Vec::<BaseMovement>::new();
     let mut base_state = base_state.clone();
     let fielder_throw_speed = decider.roll_std_dev_skill_stat(
+        Draw::ThrowSpeed,
         *levels::THROW_SPEED,
         fielding_player.fielder_throw_speed_bias,
     );
@@ -97,10 +269,34 @@ fn post_out_base_running(
     let third_runner = base_state[Consts::THIRD]
         .map(|batter_index| batting_team.player_at_batting_index(batter_index));
 
+    if let Some(first_runner) = base_state[Consts::FIRST].filter(|_| runners_going) {
+        // Caught off first, and doubled off if the throw beats them back
+        let first_runner_speed = decider.roll_std_dev_skill_stat(
+            Draw::BaserunnerSpeed,
+            *levels::BASERUNNER_SPEED,
+            batting_team
+                .player_at_batting_index(first_runner)
+                .baserunner_run_speed_bias,
+        );
+
+        let return_time = levels::LINE_DRIVE_RUNNER_LEAD / first_runner_speed;
+        let throw_time = location.distance(Location::first_base()) / fielder_throw_speed;
+        if return_time > throw_time {
+            has_thrown = true;
+            base_state[Consts::FIRST] = None;
+            outs_made += 1;
+            base_movements.push(BaseMovement {
+                starting_base: Some(Consts::FIRST),
+                bases_moved: MoveType::Out(Consts::FIRST),
+            });
+        }
+    }
+
     if base_state[Consts::THIRD].is_some() && location.distance.0 > 250.0 {
         // Runs home on the sac fly
         base_state[Consts::THIRD] = None;
         let third_runner_speed = decider.roll_std_dev_skill_stat(
+            Draw::BaserunnerSpeed,
             *levels::BASERUNNER_SPEED,
             third_runner.unwrap().baserunner_run_speed_bias,
         );
@@ -136,6 +332,7 @@ fn post_out_base_running(
         let runner = base_state[Consts::SECOND];
         base_state[Consts::SECOND] = None;
         let second_runner_speed = decider.roll_std_dev_skill_stat(
+            Draw::BaserunnerSpeed,
             *levels::BASERUNNER_SPEED,
             second_runner.unwrap().baserunner_run_speed_bias,
         );
@@ -167,15 +364,18 @@ fn post_out_base_running(
             runs_scored,
             batter_hit_type: HitType::Out,
             ending_base_state: base_state,
+            unearned_runs: 0,
         },
     }
 }
 
+#[allow(clippy::too_many_arguments)]
 fn post_throw_base_running(
     _location: &Location,
     fielding_play: &FieldingPlay,
     batter_lineup_index: u8,
     base_state: &[Option<u8>; 3],
+    runners_going: bool,
     batting_team: &Team,
     fielding_team: &Team,
     decider: &mut impl Decider,
@@ -197,19 +397,23 @@ fn post_throw_base_running(
         .map(|batting_index| batting_team.player_at_batting_index(batting_index));
 
     let fielder_throw_speed = decider.roll_std_dev_skill_stat(
+        Draw::ThrowSpeed,
         *levels::THROW_SPEED,
         fielder_player.fielder_throw_speed_bias,
     );
 
     let batter_runner_speed = decider.roll_std_dev_skill_stat(
+        Draw::BaserunnerSpeed,
         *levels::BASERUNNER_SPEED,
         batter_player.baserunner_run_speed_bias,
     );
     let batter_box_exit_time = decider.roll_std_dev_skill_stat(
+        Draw::BoxExitTime,
         *levels::BOX_EXIT_TIME,
         batter_player.baserunner_box_exit_time_bias,
     );
     let batter_base_rounding_time = decider.roll_std_dev_skill_stat(
+        Draw::BaserunnerRoundingTime,
         *levels::BASERUNNER_ROUNDING_TIME,
         -(batter_player.baserunner_rounding_time_bias.saturating_add(1)),
     );
@@ -258,6 +462,7 @@ fn post_throw_base_running(
                 let runner = base_state[Consts::SECOND];
                 base_state[Consts::SECOND] = None;
                 let second_runner_speed = decider.roll_std_dev_skill_stat(
+                    Draw::BaserunnerSpeed,
                     *levels::BASERUNNER_SPEED,
                     second_runner.unwrap().baserunner_run_speed_bias,
                 );
@@ -351,13 +556,12 @@ fn post_throw_base_running(
                     if base_state[Consts::FIRST].is_some() {
                         // Force at second
                         let first_batter_speed = decider.roll_std_dev_skill_stat(
+                            Draw::BaserunnerSpeed,
                             *levels::BASERUNNER_SPEED,
                             first_runner.unwrap().baserunner_run_speed_bias,
                         );
-                        let first_takeoff_delay = decider.roll_std_dev_skill_stat(
-                            *levels::BASE_TAKEOFF_DELAY,
-                            first_runner.unwrap().baserunner_takeoff_delay_bias,
-                        );
+                        let first_takeoff_delay =
+                            takeoff_delay(first_runner.unwrap(), runners_going, decider);
 
                         let first_to_second_run_time =
                             90.0 / first_batter_speed + first_takeoff_delay;
@@ -370,6 +574,7 @@ fn post_throw_base_running(
                             });
 
                             let second_fielder_throw_speed = decider.roll_std_dev_skill_stat(
+                                Draw::ThrowSpeed,
                                 *levels::THROW_SPEED,
                                 second_fielder_player.fielder_throw_speed_bias,
                             );
@@ -450,13 +655,12 @@ fn post_throw_base_running(
                     });
 
                     let first_batter_speed = decider.roll_std_dev_skill_stat(
+                        Draw::BaserunnerSpeed,
                         *levels::BASERUNNER_SPEED,
                         first_runner.unwrap().baserunner_run_speed_bias,
                     );
-                    let first_takeoff_delay = decider.roll_std_dev_skill_stat(
-                        *levels::BASE_TAKEOFF_DELAY,
-                        first_runner.unwrap().baserunner_takeoff_delay_bias,
-                    );
+                    let first_takeoff_delay =
+                        takeoff_delay(first_runner.unwrap(), runners_going, decider);
 
                     let first_to_second_run_time = 90.0 / first_batter_speed + first_takeoff_delay;
                     if first_to_second_run_time > fielding_play.to_event.travel_time.0
@@ -620,13 +824,12 @@ fn post_throw_base_running(
 
                         // Force at third
                         let second_runner_speed = decider.roll_std_dev_skill_stat(
+                            Draw::BaserunnerSpeed,
                             *levels::BASERUNNER_SPEED,
                             second_runner.unwrap().baserunner_run_speed_bias,
                         );
-                        let second_runner_takeoff_delay = decider.roll_std_dev_skill_stat(
-                            *levels::BASE_TAKEOFF_DELAY,
-                            second_runner.unwrap().baserunner_takeoff_delay_bias,
-                        );
+                        let second_runner_takeoff_delay =
+                            takeoff_delay(second_runner.unwrap(), runners_going, decider);
 
                         let second_to_third_run_time =
                             90.0 / second_runner_speed + second_runner_takeoff_delay;
@@ -639,10 +842,12 @@ fn post_throw_base_running(
                             });
 
                             let third_baseman_throw_speed = decider.roll_std_dev_skill_stat(
+                                Draw::ThrowSpeed,
                                 *levels::THROW_SPEED,
                                 third_baseman.fielder_throw_speed_bias,
                             );
                             let third_baseman_transfer_time = decider.roll_std_dev_skill_stat(
+                                Draw::FielderTransferTime,
                                 *levels::FIELDER_TRANSFER_TIME,
                                 third_baseman.fielder_transfer_time_bias,
                             );
@@ -661,10 +866,12 @@ fn post_throw_base_running(
                                 let second_baseman =
                                     fielding_team.player_at_position(&Fielder::SecondBase);
                                 let second_baseman_throw_speed = decider.roll_std_dev_skill_stat(
+                                    Draw::ThrowSpeed,
                                     *levels::THROW_SPEED,
                                     second_baseman.fielder_throw_speed_bias,
                                 );
                                 let second_baseman_transfer_time = decider.roll_std_dev_skill_stat(
+                                    Draw::FielderTransferTime,
                                     *levels::FIELDER_TRANSFER_TIME,
                                     second_baseman.fielder_transfer_time_bias,
                                 );
@@ -703,10 +910,12 @@ fn post_throw_base_running(
                                 });
 
                                 let third_baseman_throw_speed = decider.roll_std_dev_skill_stat(
+                                    Draw::ThrowSpeed,
                                     *levels::THROW_SPEED,
                                     third_baseman.fielder_throw_speed_bias,
                                 );
                                 let third_baseman_transfer_time = decider.roll_std_dev_skill_stat(
+                                    Draw::FielderTransferTime,
                                     *levels::FIELDER_TRANSFER_TIME,
                                     third_baseman.fielder_transfer_time_bias,
                                 );
@@ -787,13 +996,12 @@ fn post_throw_base_running(
                     });
 
                     let second_runner_speed = decider.roll_std_dev_skill_stat(
+                        Draw::BaserunnerSpeed,
                         *levels::BASERUNNER_SPEED,
                         second_runner.unwrap().baserunner_run_speed_bias,
                     );
-                    let second_runner_takeoff_delay = decider.roll_std_dev_skill_stat(
-                        *levels::BASE_TAKEOFF_DELAY,
-                        second_runner.unwrap().baserunner_takeoff_delay_bias,
-                    );
+                    let second_runner_takeoff_delay =
+                        takeoff_delay(second_runner.unwrap(), runners_going, decider);
                     let second_to_third_run_time =
                         90.0 / second_runner_speed + second_runner_takeoff_delay;
                     if second_to_third_run_time > fielding_play.to_event.travel_time.0
@@ -942,13 +1150,12 @@ fn post_throw_base_running(
                     if base_state.iter().all(|b| b.is_some()) {
                         // Force at home
                         let third_to_home_speed = decider.roll_std_dev_skill_stat(
+                            Draw::BaserunnerSpeed,
                             *levels::BASERUNNER_SPEED,
                             third_runner.unwrap().baserunner_run_speed_bias,
                         );
-                        let third_to_home_takeoff = decider.roll_std_dev_skill_stat(
-                            *levels::BASE_TAKEOFF_DELAY,
-                            third_runner.unwrap().baserunner_takeoff_delay_bias,
-                        );
+                        let third_to_home_takeoff =
+                            takeoff_delay(third_runner.unwrap(), runners_going, decider);
                         let third_to_home_run_time =
                             90.0 / third_to_home_speed + third_to_home_takeoff;
                         if third_to_home_run_time > fielding_play.to_event.travel_time.0 {
@@ -960,10 +1167,12 @@ fn post_throw_base_running(
                             });
 
                             let home_to_third_throw_time = decider.roll_std_dev_skill_stat(
+                                Draw::ThrowSpeed,
                                 *levels::THROW_SPEED,
                                 catcher.fielder_throw_speed_bias,
                             );
                             let home_transfer_time = decider.roll_std_dev_skill_stat(
+                                Draw::FielderTransferTime,
                                 *levels::FIELDER_TRANSFER_TIME,
                                 catcher.fielder_transfer_time_bias,
                             );
@@ -971,13 +1180,12 @@ fn post_throw_base_running(
                                 90.0 / home_to_third_throw_time + home_transfer_time;
 
                             let second_to_third_speed = decider.roll_std_dev_skill_stat(
+                                Draw::BaserunnerSpeed,
                                 *levels::BASERUNNER_SPEED,
                                 second_runner.unwrap().baserunner_run_speed_bias,
                             );
-                            let second_to_third_takeoff = decider.roll_std_dev_skill_stat(
-                                *levels::BASE_TAKEOFF_DELAY,
-                                second_runner.unwrap().baserunner_takeoff_delay_bias,
-                            );
+                            let second_to_third_takeoff =
+                                takeoff_delay(second_runner.unwrap(), runners_going, decider);
                             let second_to_third_run_time =
                                 90.0 / second_to_third_speed + second_to_third_takeoff;
                             if second_to_third_run_time
@@ -992,10 +1200,12 @@ fn post_throw_base_running(
 
                                 let third_to_first_distance = 90.0 * 2.0f64.sqrt();
                                 let third_to_first_throw_speed = decider.roll_std_dev_skill_stat(
+                                    Draw::ThrowSpeed,
                                     *levels::THROW_SPEED,
                                     third_baseman.fielder_throw_speed_bias,
                                 );
                                 let third_to_first_transfer = decider.roll_std_dev_skill_stat(
+                                    Draw::FielderTransferTime,
                                     *levels::FIELDER_TRANSFER_TIME,
                                     third_baseman.fielder_transfer_time_bias,
                                 );
@@ -1040,10 +1250,12 @@ fn post_throw_base_running(
                                 });
 
                                 let home_first_throw_speed = decider.roll_std_dev_skill_stat(
+                                    Draw::ThrowSpeed,
                                     *levels::THROW_SPEED,
                                     catcher.fielder_throw_speed_bias,
                                 );
                                 let home_first_transfer = decider.roll_std_dev_skill_stat(
+                                    Draw::FielderTransferTime,
                                     *levels::FIELDER_TRANSFER_TIME,
                                     catcher.fielder_transfer_time_bias,
                                 );
@@ -1138,13 +1350,12 @@ fn post_throw_base_running(
 
                     if base_state[Consts::SECOND].is_some() {
                         let second_run_speed = decider.roll_std_dev_skill_stat(
+                            Draw::BaserunnerSpeed,
                             *levels::BASERUNNER_SPEED,
                             second_runner.unwrap().baserunner_run_speed_bias,
                         );
-                        let second_takeoff = decider.roll_std_dev_skill_stat(
-                            *levels::BASE_TAKEOFF_DELAY,
-                            second_runner.unwrap().baserunner_takeoff_delay_bias,
-                        );
+                        let second_takeoff =
+                            takeoff_delay(second_runner.unwrap(), runners_going, decider);
                         let second_home_run_time = (2.0 * 90.0) / second_run_speed + second_takeoff;
 
                         if second_home_run_time > fielding_play.to_event.travel_time.0 {
@@ -1285,6 +1496,7 @@ fn post_throw_base_running(
             runs_scored,
             batter_hit_type,
             ending_base_state: base_state,
+            unearned_runs: 0,
         },
     }
 }
@@ -0,0 +1,113 @@
+use std::fmt::Display;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use ts_rs::TS;
+
+use crate::prelude::*;
+
+/// The weather on game day. `Default` is a calm, mild day.
+#[derive(Debug, Clone, Copy, PartialEq, TS)]
+#[ts(export)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct GameConditions {
+    /// Miles per hour
+    pub wind_speed: u8,
+    /// Where the wind blows toward, measured like a hit direction. 45 blows
+    /// straight out to center field, and 225 straight in from it.
+    pub wind_direction: HitDirection,
+
+    /// Degrees Fahrenheit. The ball carries further in warm air.
+    pub temperature: i16,
+    /// Relative humidity, in percent. Humid air is thinner than dry air.
+    pub humidity: u8,
+
+    pub roof: Roof,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, TS)]
+#[ts(export)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Roof {
+    /// Including parks without one
+    #[default]
+    Open,
+    /// Keeps the wind out
+    Closed,
+}
+
+/// Pascals at sea level
+const SEA_LEVEL_PRESSURE: f64 = 101_325.0;
+/// Feet of altitude over which the air pressure falls by a factor of e
+const PRESSURE_SCALE_HEIGHT: f64 = 27_700.0;
+/// Joules per kilogram kelvin
+const DRY_AIR_GAS_CONSTANT: f64 = 287.05;
+const WATER_VAPOR_GAS_CONSTANT: f64 = 461.5;
+const POUNDS_PER_CUBIC_FOOT_PER_KILOGRAM_PER_CUBIC_METER: f64 = 0.062_428;
+
+impl GameConditions {
+    /// Pounds per cubic foot of air in the park, thinned by its altitude
+    pub fn air_density(&self, ballpark: &Ballpark) -> f64 {
+        let celsius = (self.temperature as f64 - 32.0) * 5.0 / 9.0;
+        let kelvin = celsius + 273.15;
+
+        let pressure =
+            SEA_LEVEL_PRESSURE * (-(ballpark.altitude as f64) / PRESSURE_SCALE_HEIGHT).exp();
+        // Tetens' formula for the vapor pressure of saturated air
+        let saturated_vapor_pressure = 610.78 * (17.27 * celsius / (celsius + 237.3)).exp();
+        let vapor_pressure = saturated_vapor_pressure * self.humidity.min(100) as f64 / 100.0;
+
+        let density = (pressure - vapor_pressure) / (DRY_AIR_GAS_CONSTANT * kelvin)
+            + vapor_pressure / (WATER_VAPOR_GAS_CONSTANT * kelvin);
+        density * POUNDS_PER_CUBIC_FOOT_PER_KILOGRAM_PER_CUBIC_METER
+    }
+
+    /// Feet per second of wind the ball flies through, none under a closed roof
+    pub fn wind_speed(&self) -> Speed {
+        match self.roof {
+            Roof::Open => Speed(self.wind_speed as f64 * 5280.0 / 3600.0),
+            Roof::Closed => Speed(0.0),
+        }
+    }
+}
+
+impl Default for GameConditions {
+    fn default() -> Self {
+        Self {
+            wind_speed: 0,
+            wind_direction: HitDirection(45.0),
+            temperature: 70,
+            humidity: 50,
+            roof: Roof::Open,
+        }
+    }
+}
+
+/// Game day weather as a broadcast would give it, e.g. "72°F and 50% humidity,
+/// wind 10 mph out to center"
+impl Display for GameConditions {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.roof == Roof::Closed {
+            return write!(f, "{}°F under a closed roof", self.temperature);
+        }
+
+        write!(f, "{}°F and {}% humidity, ", self.temperature, self.humidity)?;
+        if self.wind_speed == 0 {
+            return write!(f, "no wind");
+        }
+
+        // Measured from straight out to center field
+        let toward = (self.wind_direction.0 - 45.0).rem_euclid(360.0);
+        let wind = match toward {
+            angle if angle <= 30.0 || angle >= 330.0 => "out to center",
+            angle if angle < 75.0 => "out to right",
+            angle if angle <= 105.0 => "left to right",
+            angle if angle < 150.0 => "in from left",
+            angle if angle <= 210.0 => "in from center",
+            angle if angle < 255.0 => "in from right",
+            angle if angle <= 285.0 => "right to left",
+            _ => "out to left",
+        };
+        write!(f, "wind {} mph {}", self.wind_speed, wind)
+    }
+}
@@ -0,0 +1,116 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use strum_macros::EnumIter;
+use ts_rs::TS;
+
+use crate::prelude::*;
+
+/// Names each random draw the simulation asks a Decider for, so Deciders can
+/// target, log or bias individual draws without inspecting their arguments.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, EnumIter, TS)]
+#[ts(export)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Draw {
+    /// Uniform roll over the pitcher's repertoire usage, only drawn when they have more than one pitch
+    PitchType,
+    /// Flip for whether a pitch is thrown outside the zone, or aimed outside
+    /// it when the Decider rolls plate locations
+    PitchIsBall,
+    SwingOnBall,
+    ContactOnBallSwing,
+    FoulOnBallContact,
+    SwingOnStrike,
+    ContactOnStrikeSwing,
+    FoulOnStrikeContact,
+    /// Flip on a taken pitch near the edge of the zone for whether it is called a strike
+    CalledStrike,
+    /// Flip on a taken inside ball for whether it hits the batter
+    HitByPitch,
+    /// Flips on a taken ball with runners on for whether it gets away from the catcher
+    WildPitch,
+    PassedBall,
+    /// Flip on a swing for whether the bat catches the catcher's glove
+    CatcherInterference,
+    /// Flip on a third strike the batter can run on for whether the catcher fails to hold it
+    DroppedThirdStrike,
+    /// Uniform roll for how far a dropped third strike gets away from the catcher
+    DroppedThirdStrikeCarom,
+    /// Flips on a bunt attempt at a strike for whether it is put down, and kept fair
+    BuntContact,
+    BuntFoul,
+
+    /// Uniform roll for the angle the ball is hit off the bat
+    HitDirection,
+    HitLaunchAngle,
+    HitExitSpeed,
+    /// Spin off the bat either side of what the launch angle and direction put on
+    HitBackspin,
+    HitSidespin,
+    /// Uniform roll for the angle a bunt is pushed off the bat
+    BuntDirection,
+    BuntLaunchAngle,
+    BuntExitSpeed,
+    /// Uniform roll for the angle a foul ball leaves the bat, outside the lines
+    FoulDirection,
+    FoulLaunchAngle,
+    FoulExitSpeed,
+
+    BaserunnerSpeed,
+    BaserunnerRoundingTime,
+    BoxExitTime,
+    BaseTakeoffDelay,
+
+    FielderSpeed,
+    ThrowSpeed,
+    PlayerReactionTime,
+    FielderTransferTime,
+    /// Flip on a ball that should be an out for whether the fielder misplays it
+    FieldingError,
+    /// Flip on a throw for whether it gets away
+    ThrowingError,
+}
+
+impl Draw {
+    /// The `levels` stat a draw is rolled against, if it is a stat draw
+    pub fn stat(&self) -> Option<Stat> {
+        match self {
+            Draw::HitLaunchAngle => Some(*levels::HIT_LAUNCH_ANGLE),
+            Draw::HitExitSpeed => Some(*levels::HIT_EXIT_SPEED),
+            Draw::HitBackspin | Draw::HitSidespin => Some(*levels::HIT_SPIN_SPREAD),
+            Draw::BuntLaunchAngle => Some(*levels::BUNT_LAUNCH_ANGLE),
+            Draw::BuntExitSpeed => Some(*levels::BUNT_EXIT_SPEED),
+            Draw::FoulLaunchAngle => Some(*levels::FOUL_LAUNCH_ANGLE),
+            Draw::FoulExitSpeed => Some(*levels::FOUL_EXIT_SPEED),
+            Draw::BaserunnerSpeed => Some(*levels::BASERUNNER_SPEED),
+            Draw::BaserunnerRoundingTime => Some(*levels::BASERUNNER_ROUNDING_TIME),
+            Draw::BoxExitTime => Some(*levels::BOX_EXIT_TIME),
+            Draw::BaseTakeoffDelay => Some(*levels::BASE_TAKEOFF_DELAY),
+            Draw::FielderSpeed => Some(*levels::FIELDER_SPEED),
+            Draw::ThrowSpeed => Some(*levels::THROW_SPEED),
+            Draw::PlayerReactionTime => Some(*levels::PLAYER_REACTION_TIME),
+            Draw::FielderTransferTime => Some(*levels::FIELDER_TRANSFER_TIME),
+            Draw::PitchType
+            | Draw::PitchIsBall
+            | Draw::SwingOnBall
+            | Draw::ContactOnBallSwing
+            | Draw::FoulOnBallContact
+            | Draw::SwingOnStrike
+            | Draw::ContactOnStrikeSwing
+            | Draw::FoulOnStrikeContact
+            | Draw::CalledStrike
+            | Draw::HitByPitch
+            | Draw::WildPitch
+            | Draw::PassedBall
+            | Draw::CatcherInterference
+            | Draw::DroppedThirdStrike
+            | Draw::DroppedThirdStrikeCarom
+            | Draw::BuntContact
+            | Draw::BuntFoul
+            | Draw::HitDirection
+            | Draw::BuntDirection
+            | Draw::FoulDirection
+            | Draw::FieldingError
+            | Draw::ThrowingError => None,
+        }
+    }
+}
@@ -7,7 +7,11 @@ use strum::IntoEnumIterator;
 use strum_macros::EnumIter;
 use ts_rs::TS;
 
-use crate::{base_running, prelude::*};
+use crate::{
+    base_running,
+    flight::Trajectory,
+    prelude::*,
+};
 
 #[derive(Clone, Copy, PartialEq, Eq, Debug, EnumIter, TS)]
 #[ts(export)]
@@ -92,6 +96,26 @@ impl Display for Fielder {
 pub struct FieldingRecord {
     pub landing: BallLanding,
     pub base_running_record: BaseRunningRecord,
+    pub error: Option<FieldingError>,
+}
+
+/// A misplay charged to a fielder
+#[derive(Clone, Copy, PartialEq, Eq, Debug, TS)]
+#[ts(export)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct FieldingError {
+    pub fielder: Fielder,
+    pub error_type: ErrorType,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug, TS)]
+#[ts(export)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum ErrorType {
+    /// Booted a ball that should have been an out, or dropped a catch
+    Fielding,
+    /// Threw the ball away, letting everyone take an extra base
+    Throwing,
 }
 
 #[derive(Clone, PartialEq, Debug, TS)]
@@ -128,39 +152,240 @@ pub struct FieldingPlay {
     pub base: usize,
 }
 
+/// Balls that curve foul are fielded like any other foul, returning `None`
+/// when they are not caught
+#[allow(clippy::too_many_arguments)]
 pub fn simulate_fielding(
     direction: HitDirection,
     launch_angle: LaunchAngle,
     exit_speed: Speed,
+    spin: Spin,
     batter_lineup_index: u8,
     batting_team: &Team,
     fielding_team: &Team,
+    alignment: &DefensiveAlignment,
+    ballpark: &Ballpark,
+    conditions: &GameConditions,
     base_state: &[Option<u8>; 3],
+    runners_going: bool,
     decider: &mut impl Decider,
-) -> HitOutcome {
-    let hit_locations = ball_path(direction, launch_angle, exit_speed);
-
-    if (hit_locations.catchable_path.location.direction.0 < 30.0
-        && hit_locations.catchable_path.location.distance.0 > 400.0)
-        || (30.0 <= hit_locations.catchable_path.location.direction.0
-            && hit_locations.catchable_path.location.direction.0 < 60.0
-            && hit_locations.catchable_path.location.distance.0 > 430.0)
-        || (60.0 <= hit_locations.catchable_path.location.direction.0
-            && hit_locations.catchable_path.location.distance.0 > 400.0)
-    {
-        return HitOutcome::HomeRun;
+) -> Option<HitOutcome> {
+    let trajectory = Trajectory::new(
+        direction,
+        launch_angle,
+        exit_speed,
+        spin,
+        PLAYER_HEIGHT,
+        ballpark,
+        conditions,
+    );
+    let mut hit_locations = ball_path(&trajectory);
+
+    // Measured where the ball curves to, rather than where it was hit
+    let landed_direction = hit_locations.landed_path.location.direction;
+    // Checked before the fence, which clamps the direction back into fair territory
+    if landed_direction.is_foul() {
+        return foul_fielding(
+            &hit_locations,
+            launch_angle,
+            batter_lineup_index,
+            batting_team,
+            fielding_team,
+            alignment,
+            ballpark,
+            base_state,
+            runners_going,
+            decider,
+        )
+        .map(HitOutcome::InPlay);
+    }
+
+    let fence_distance = ballpark.fence_distance(landed_direction);
+    if hit_locations.landed_path.location.distance.0 > fence_distance.0 {
+        let height_at_fence = trajectory
+            .height_at_distance(fence_distance)
+            .expect("Ball landed past the fence without reaching it");
+        if height_at_fence > ballpark.wall_height(landed_direction) {
+            return Some(HitOutcome::HomeRun);
+        }
+
+        // Off the wall, where it drops
+        hit_locations.landed_path = FieldingEvent {
+            location: Location {
+                direction: landed_direction,
+                distance: fence_distance,
+            },
+            travel_time: TravelTime(
+                hit_locations.landed_path.travel_time.0 * fence_distance.0
+                    / hit_locations.landed_path.location.distance.0,
+            ),
+        };
+    }
+
+    // Balls that hit the wall above a fielder's reach can't be caught
+    let catchers = if hit_locations.catchable_path.location.distance.0 < fence_distance.0 {
+        catching_fielders(&hit_locations, fielding_team, alignment, decider)
+    } else {
+        Vec::new()
+    };
+
+    if let Some((fielder, (location, range))) = catchers.first() {
+        let landing = BallLanding::Out(*fielder, location.clone());
+        let base_running_record = base_running::simulate_base_running(
+            batter_lineup_index,
+            batting_team,
+            fielding_team,
+            &landing,
+            base_state,
+            runners_going && is_line_drive(launch_angle),
+            decider,
+        );
+        let (base_running_record, error) = check_for_error(
+            base_running_record,
+            *range,
+            &landing,
+            batter_lineup_index,
+            base_state,
+            fielding_team,
+            decider,
+        );
+
+        Some(HitOutcome::InPlay(FieldingRecord {
+            landing,
+            base_running_record,
+            error,
+        }))
+    } else {
+        Some(HitOutcome::InPlay(hit(
+            batter_lineup_index,
+            batting_team,
+            fielding_team,
+            alignment,
+            ballpark,
+            hit_locations.landed_path,
+            base_state,
+            runners_going,
+            decider,
+        )))
+    }
+}
+
+/// A foul ball is only fielded if it is caught short of the stands. Uncaught
+/// fouls return `None`, leaving the at bat to count them.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn simulate_foul_fielding(
+    direction: HitDirection,
+    launch_angle: LaunchAngle,
+    exit_speed: Speed,
+    spin: Spin,
+    batter_lineup_index: u8,
+    batting_team: &Team,
+    fielding_team: &Team,
+    alignment: &DefensiveAlignment,
+    ballpark: &Ballpark,
+    conditions: &GameConditions,
+    base_state: &[Option<u8>; 3],
+    runners_going: bool,
+    decider: &mut impl Decider,
+) -> Option<FieldingRecord> {
+    let hit_locations = ball_path(&Trajectory::new(
+        direction,
+        launch_angle,
+        exit_speed,
+        spin,
+        PLAYER_HEIGHT,
+        ballpark,
+        conditions,
+    ));
+
+    foul_fielding(
+        &hit_locations,
+        launch_angle,
+        batter_lineup_index,
+        batting_team,
+        fielding_team,
+        alignment,
+        ballpark,
+        base_state,
+        runners_going,
+        decider,
+    )
+}
+
+#[allow(clippy::too_many_arguments)]
+fn foul_fielding(
+    hit_locations: &HitLocations,
+    launch_angle: LaunchAngle,
+    batter_lineup_index: u8,
+    batting_team: &Team,
+    fielding_team: &Team,
+    alignment: &DefensiveAlignment,
+    ballpark: &Ballpark,
+    base_state: &[Option<u8>; 3],
+    runners_going: bool,
+    decider: &mut impl Decider,
+) -> Option<FieldingRecord> {
+    let foul_territory = &ballpark.foul_territory;
+
+    // The ball only gets further into the stands as it comes down
+    if !foul_territory.contains(hit_locations.catchable_path.location) {
+        return None;
     }
 
+    let (fielder, (location, range)) =
+        catching_fielders(hit_locations, fielding_team, alignment, decider)
+            .into_iter()
+            .find(|(_, (location, _))| foul_territory.contains(*location))?;
+    let landing = BallLanding::Out(fielder, location);
+    let base_running_record = base_running::simulate_base_running(
+        batter_lineup_index,
+        batting_team,
+        fielding_team,
+        &landing,
+        base_state,
+        runners_going && is_line_drive(launch_angle),
+        decider,
+    );
+    let (base_running_record, error) = check_for_error(
+        base_running_record,
+        range,
+        &landing,
+        batter_lineup_index,
+        base_state,
+        fielding_team,
+        decider,
+    );
+
+    Some(FieldingRecord {
+        landing,
+        base_running_record,
+        error,
+    })
+}
+
+/// Caught before runners going with the pitch can read it and get back
+fn is_line_drive(launch_angle: LaunchAngle) -> bool {
+    launch_angle.0 < levels::LINE_DRIVE_MAX_ANGLE
+}
+
+/// Fielders who can get under the ball before it lands, closest first
+fn catching_fielders(
+    hit_locations: &HitLocations,
+    fielding_team: &Team,
+    alignment: &DefensiveAlignment,
+    decider: &mut impl Decider,
+) -> Vec<(Fielder, (Location, Distance))> {
     let mut eligible_fielders: Vec<_> = Fielder::iter()
         .map(|fielder| {
             (
-                fielder.clone(),
-                player_distance_to_catch_hit(&fielder, &hit_locations),
+                fielder,
+                player_distance_to_catch_hit(alignment.location(&fielder), hit_locations),
             )
         })
         .filter(|(fielder, distance)| {
             (distance.1 .0
                 / decider.roll_std_dev_mult_skill_stat(
+                    Draw::FielderSpeed,
                     *levels::FIELDER_SPEED,
                     fielding_team
                         .player_at_position(fielder)
@@ -175,44 +400,286 @@ pub fn simulate_fielding(
         lh_distance.0.partial_cmp(&rh_distance.0).unwrap()
     });
 
-    if let Some((fielder, (location, _))) = eligible_fielders.first() {
-        let landing = BallLanding::Out(*fielder, location.clone());
-        let base_running_record = base_running::simulate_base_running(
-            batter_lineup_index,
-            batting_team,
-            fielding_team,
-            &landing,
-            base_state,
-            decider,
-        );
+    eligible_fielders
+}
 
-        HitOutcome::InPlay(FieldingRecord {
-            landing,
-            base_running_record,
+/// Fielders who charge a bunt
+const BUNT_FIELDERS: [Fielder; 4] = [
+    Fielder::Catcher,
+    Fielder::Pitcher,
+    Fielder::FirstBase,
+    Fielder::ThirdBase,
+];
+
+pub(crate) struct BuntPath {
+    /// Where a bunt popped up lands, catchable until then
+    popped_up: Option<FieldingEvent>,
+    /// Where the bunt stops rolling, or lands if popped up
+    rest: FieldingEvent,
+}
+
+pub(crate) fn bunt_path(
+    direction: HitDirection,
+    launch_angle: LaunchAngle,
+    exit_speed: Speed,
+) -> BuntPath {
+    let bat_height = 3.0_f64;
+
+    // Too slow for the park or the weather to make a difference
+    let landed = Trajectory::new(
+        direction,
+        launch_angle,
+        exit_speed,
+        Spin::default(),
+        bat_height,
+        &Ballpark::default(),
+        &GameConditions::default(),
+    )
+    .landing();
+    let ground_speed = launch_angle.0.to_radians().cos() * exit_speed.0;
+    let landed_distance = landed.location.distance.0;
+    let landed_travel_time = landed.travel_time;
+
+    if launch_angle.0 > levels::BUNT_POP_UP_ANGLE {
+        // Dies where it lands
+        BuntPath {
+            popped_up: Some(landed.clone()),
+            rest: landed,
+        }
+    } else {
+        let roll_time = ground_speed / levels::BUNT_ROLL_DECELERATION;
+        let roll_distance = ground_speed * roll_time / 2.0;
+
+        BuntPath {
+            popped_up: None,
+            rest: FieldingEvent {
+                location: Location {
+                    direction,
+                    distance: Distance(landed_distance + roll_distance),
+                },
+                travel_time: TravelTime(landed_travel_time.0 + roll_time),
+            },
+        }
+    }
+}
+
+/// Pop ups are caught if a charging fielder gets under them. Otherwise the
+/// quickest fielder to where the bunt stops takes the sure out at first.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn simulate_bunt_fielding(
+    path: &BuntPath,
+    batter_lineup_index: u8,
+    batting_team: &Team,
+    fielding_team: &Team,
+    alignment: &DefensiveAlignment,
+    base_state: &[Option<u8>; 3],
+    runners_going: bool,
+    decider: &mut impl Decider,
+) -> FieldingRecord {
+    if let Some(popped_up) = &path.popped_up {
+        let mut eligible_fielders: Vec<_> = BUNT_FIELDERS
+            .into_iter()
+            .map(|fielder| {
+                (
+                    fielder,
+                    Distance(alignment.location(&fielder).distance(popped_up.location)),
+                )
+            })
+            .filter(|(fielder, distance)| {
+                let player = fielding_team.player_at_position(fielder);
+                let reaction_time = decider.roll_std_dev_skill_stat(
+                    Draw::PlayerReactionTime,
+                    *levels::PLAYER_REACTION_TIME,
+                    player.fielder_reaction_time_bias,
+                );
+                let player_speed = decider.roll_std_dev_skill_stat(
+                    Draw::FielderSpeed,
+                    *levels::FIELDER_SPEED,
+                    player.fielder_run_speed_bias,
+                );
+
+                distance.0 / player_speed + reaction_time < popped_up.travel_time.0
+            })
+            .collect();
+
+        eligible_fielders.sort_by(|(_, lh_distance), (_, rh_distance)| {
+            lh_distance.0.partial_cmp(&rh_distance.0).unwrap()
+        });
+
+        if let Some((fielder, range)) = eligible_fielders.first() {
+            let landing = BallLanding::Out(*fielder, popped_up.location);
+            let base_running_record = base_running::simulate_base_running(
+                batter_lineup_index,
+                batting_team,
+                fielding_team,
+                &landing,
+                base_state,
+                runners_going,
+                decider,
+            );
+            let (base_running_record, error) = check_for_error(
+                base_running_record,
+                *range,
+                &landing,
+                batter_lineup_index,
+                base_state,
+                fielding_team,
+                decider,
+            );
+
+            return FieldingRecord {
+                landing,
+                base_running_record,
+                error,
+            };
+        }
+    }
+
+    let mut fielder_times: Vec<_> = BUNT_FIELDERS
+        .into_iter()
+        .map(|fielder| {
+            let player = fielding_team.player_at_position(&fielder);
+            let reaction_time = decider.roll_std_dev_skill_stat(
+                Draw::PlayerReactionTime,
+                *levels::PLAYER_REACTION_TIME,
+                player.fielder_reaction_time_bias,
+            );
+            let player_speed = decider.roll_std_dev_skill_stat(
+                Draw::FielderSpeed,
+                *levels::FIELDER_SPEED,
+                player.fielder_run_speed_bias,
+            );
+            let fielder_transfer = decider.roll_std_dev_skill_stat(
+                Draw::FielderTransferTime,
+                *levels::FIELDER_TRANSFER_TIME,
+                player.fielder_transfer_time_bias,
+            );
+            let distance = alignment.location(&fielder).distance(path.rest.location);
+
+            // Charges to where the ball stops, picking it up once it gets there
+            let fielded_at_time =
+                (distance / player_speed + reaction_time).max(path.rest.travel_time.0);
+
+            (
+                fielder,
+                Distance(distance),
+                TravelTime(fielded_at_time + fielder_transfer),
+            )
         })
+        .collect();
+
+    fielder_times.sort_by(|(_, _, lh_time), (_, _, rh_time)| {
+        lh_time.0.partial_cmp(&rh_time.0).unwrap()
+    });
+
+    let (fielder, distance, fielded_at_time) = fielder_times[0];
+    // The second baseman covers first when the first baseman charges
+    let to_fielder = if fielder == Fielder::FirstBase {
+        Fielder::SecondBase
     } else {
-        HitOutcome::InPlay(hit(
-            batter_lineup_index,
-            batting_team,
-            fielding_team,
-            hit_locations.landed_path,
-            base_state,
-            decider,
-        ))
+        Fielder::FirstBase
+    };
+    let fielding_play = throw_to_force(
+        fielded_at_time,
+        fielder,
+        Location::first_base(),
+        to_fielder,
+        fielder,
+        distance,
+        path.rest.location,
+        Consts::FIRST,
+        fielding_team,
+        decider,
+    );
+
+    let landing = BallLanding::Landed(path.rest.location, fielding_play);
+    let base_running_record = base_running::simulate_base_running(
+        batter_lineup_index,
+        batting_team,
+        fielding_team,
+        &landing,
+        base_state,
+        runners_going,
+        decider,
+    );
+    let (base_running_record, error) = check_for_error(
+        base_running_record,
+        distance,
+        &landing,
+        batter_lineup_index,
+        base_state,
+        fielding_team,
+        decider,
+    );
+
+    FieldingRecord {
+        landing,
+        base_running_record,
+        error,
     }
 }
 
+/// The catcher chasing down a dropped third strike behind the plate and
+/// throwing to first
+pub(crate) fn dropped_third_strike_play(
+    fielding_team: &Team,
+    decider: &mut impl Decider,
+) -> FieldingPlay {
+    let catcher = fielding_team.player_at_position(&Fielder::Catcher);
+    let carom = decider.roll_uniform(
+        Draw::DroppedThirdStrikeCarom,
+        0.0..levels::MAX_DROPPED_THIRD_STRIKE_CAROM,
+    );
+    let fielded_at = Location {
+        direction: HitDirection(225.0),
+        distance: Distance(carom),
+    };
+
+    let reaction_time = decider.roll_std_dev_skill_stat(
+        Draw::PlayerReactionTime,
+        *levels::PLAYER_REACTION_TIME,
+        catcher.fielder_reaction_time_bias,
+    );
+    let player_speed = decider.roll_std_dev_skill_stat(
+        Draw::FielderSpeed,
+        *levels::FIELDER_SPEED,
+        catcher.fielder_run_speed_bias,
+    );
+    let fielder_transfer = decider.roll_std_dev_skill_stat(
+        Draw::FielderTransferTime,
+        *levels::FIELDER_TRANSFER_TIME,
+        catcher.fielder_transfer_time_bias,
+    );
+
+    throw_to_force(
+        TravelTime(carom / player_speed + reaction_time + fielder_transfer),
+        Fielder::Catcher,
+        Location::first_base(),
+        Fielder::FirstBase,
+        Fielder::Catcher,
+        Distance(carom),
+        fielded_at,
+        Consts::FIRST,
+        fielding_team,
+        decider,
+    )
+}
+
+#[allow(clippy::too_many_arguments)]
 fn hit(
     batter_lineup_index: u8,
     batting_team: &Team,
     fielding_team: &Team,
+    alignment: &DefensiveAlignment,
+    ballpark: &Ballpark,
     landed_at: FieldingEvent,
     base_state: &[Option<u8>; 3],
+    runners_going: bool,
     decider: &mut impl Decider,
 ) -> FieldingRecord {
     let force_play_index = force_play(base_state);
     let (closest_fielder, fielded_at, distance_to_fielding, time_fielding) =
-        closest_fielder(&landed_at, fielding_team, decider);
+        closest_fielder(&landed_at, fielding_team, alignment, ballpark, decider);
     let fielding_play = match force_play_index {
         0 => force_at_first(
             closest_fielder,
@@ -258,13 +725,121 @@ fn hit(
         fielding_team,
         &BallLanding::Landed(fielded_at, fielding_play.clone()),
         base_state,
+        runners_going,
+        decider,
+    );
+    let landing = BallLanding::Landed(landed_at.location, fielding_play);
+    let (base_running_record, error) = check_for_error(
+        base_running_record,
+        distance_to_fielding,
+        &landing,
+        batter_lineup_index,
+        base_state,
+        fielding_team,
         decider,
     );
 
     FieldingRecord {
-        landing: BallLanding::Landed(landed_at.location, fielding_play),
+        landing,
         base_running_record,
+        error,
+    }
+}
+
+/// Gives the fielding team a chance to botch a play, harder the further the
+/// fielder ranged and the longer the throw. An error on a play that should
+/// have put the batter out, dropped catches included, puts everyone on. One
+/// that should have put a runner out leaves that runner safe, and a throw
+/// that gets away lets everyone take an extra base. Only throws that would
+/// have made an out can get away, not a ball coming back in after a hit.
+fn check_for_error(
+    base_running_record: BaseRunningRecord,
+    range: Distance,
+    landing: &BallLanding,
+    batter_lineup_index: u8,
+    base_state: &[Option<u8>; 3],
+    fielding_team: &Team,
+    decider: &mut impl Decider,
+) -> (BaseRunningRecord, Option<FieldingError>) {
+    let (fielder, fielding_play) = match landing {
+        BallLanding::Out(fielder, _) => (*fielder, None),
+        BallLanding::Landed(_, fielding_play) => (fielding_play.from, Some(fielding_play)),
+    };
+    let player = fielding_team.player_at_position(&fielder);
+    let should_be_out = base_running_record.outcome.outs_made > 0;
+    // Catches record no movement for the batter
+    let batter_out = fielding_play.is_none()
+        || base_running_record.movements.iter().any(|movement| {
+            movement.starting_base.is_none() && matches!(movement.bases_moved, MoveType::Out(_))
+        });
+
+    if should_be_out
+        && decider.flip(
+            Draw::FieldingError,
+            levels::FIELDING_ERRORS_PER_CHANCE * (1.0 + range.0 / levels::FIELDING_ERROR_RANGE),
+            player.fielder_hands_bias,
+        )
+    {
+        let base_running_record = if batter_out {
+            base_running::reached_on_error(
+                batter_lineup_index,
+                base_state,
+                1,
+                &base_running_record.outcome,
+            )
+        } else {
+            base_running::runners_safe_on_error(base_state, base_running_record)
+        };
+
+        return (
+            base_running_record,
+            Some(FieldingError {
+                fielder,
+                error_type: ErrorType::Fielding,
+            }),
+        );
+    }
+
+    // Nothing to throw away on a clean catch or a base hit
+    let Some(fielding_play) = fielding_play.filter(|_| should_be_out) else {
+        return (base_running_record, None);
+    };
+
+    let throw_distance = fielding_play
+        .from_event
+        .location
+        .distance(fielding_play.to_event.location);
+    let throwing_error_probability = levels::THROWING_ERRORS_PER_THROW
+        * (1.0 + throw_distance / levels::THROWING_ERROR_DISTANCE);
+    if decider.flip(
+        Draw::ThrowingError,
+        throwing_error_probability,
+        player.fielder_throw_accuracy_bias,
+    ) {
+        let base_running_record = if batter_out {
+            base_running::reached_on_error(
+                batter_lineup_index,
+                base_state,
+                2,
+                &base_running_record.outcome,
+            )
+        } else {
+            base_running::extra_base_on_error(base_running::runners_safe_on_error(
+                base_state,
+                base_running_record,
+            ))
+        };
+
+        return (
+            base_running_record,
+            Some(FieldingError {
+                fielder,
+                error_type: ErrorType::Throwing,
+            }),
+        );
     }
+
+    (base_running_record, None)
 }
 
 fn throw_to_force(
@@ -283,7 +858,7 @@ fn throw_to_force(
     let throw_time = TravelTime(
         (fielded_at.distance(to_location)
             / decider
-                .roll_std_dev_skill_stat(*levels::THROW_SPEED, player.fielder_throw_speed_bias))
+                .roll_std_dev_skill_stat(Draw::ThrowSpeed, *levels::THROW_SPEED, player.fielder_throw_speed_bias))
             + throw_start_time.0,
     );
 
@@ -426,18 +1001,21 @@ fn force_play(base_state: &[Option<u8>; 3]) -> usize {
 fn closest_fielder(
     event: &FieldingEvent,
     fielding_team: &Team,
+    alignment: &DefensiveAlignment,
+    ballpark: &Ballpark,
     decider: &mut impl Decider,
 ) -> (Fielder, Location, Distance, TravelTime) {
     let mut fielder_distances: Vec<_> = Fielder::iter()
         .filter_map(|fielder| {
             let player = fielding_team.player_at_position(&fielder);
             let reaction_time = decider.roll_std_dev_skill_stat(
+                Draw::PlayerReactionTime,
                 *levels::PLAYER_REACTION_TIME,
                 player.fielder_reaction_time_bias,
             );
 
             let player_speed = decider
-                .roll_std_dev_skill_stat(*levels::FIELDER_SPEED, player.fielder_run_speed_bias);
+                .roll_std_dev_skill_stat(Draw::FielderSpeed, *levels::FIELDER_SPEED, player.fielder_run_speed_bias);
 
             let ball_speed = event.location.distance.0 / event.travel_time.0;
             let ball_location_on_reaction = Location {
@@ -445,7 +1023,7 @@ fn closest_fielder(
                 distance: Distance(ball_speed * reaction_time),
             };
 
-            let starting_location = Cartesian::from(fielder.starting_location());
+            let starting_location = Cartesian::from(alignment.location(&fielder));
             if let Some(fielding_location) = starting_location.fielding_location(
                 player_speed,
                 ball_location_on_reaction.into(),
@@ -463,6 +1041,7 @@ fn closest_fielder(
                     let travel_distance = fielding_cartesian.sub(starting_location).magnitude();
 
                     let fielder_transfer = decider.roll_std_dev_skill_stat(
+                        Draw::FielderTransferTime,
                         *levels::FIELDER_TRANSFER_TIME,
                         player.fielder_transfer_time_bias,
                     );
@@ -493,11 +1072,11 @@ fn closest_fielder(
             .filter_map(|fielder| {
                 let player = fielding_team.player_at_position(&fielder);
                 let player_speed = decider
-                    .roll_std_dev_skill_stat(*levels::FIELDER_SPEED, player.fielder_run_speed_bias);
+                    .roll_std_dev_skill_stat(Draw::FielderSpeed, *levels::FIELDER_SPEED, player.fielder_run_speed_bias);
 
                 let ball_speed = event.location.distance.0 / event.travel_time.0;
 
-                let starting_location = Cartesian::from(fielder.starting_location());
+                let starting_location = Cartesian::from(alignment.location(&fielder));
                 if let Some(fielding_location) = starting_location.fielding_location(
                     player_speed,
                     event.location.into(),
@@ -514,6 +1093,7 @@ fn closest_fielder(
                         let travel_distance = fielding_location.sub(starting_location).magnitude();
 
                         let fielder_transfer = decider.roll_std_dev_skill_stat(
+                            Draw::FielderTransferTime,
                             *levels::FIELDER_TRANSFER_TIME,
                             player.fielder_transfer_time_bias,
                         );
@@ -541,19 +1121,12 @@ fn closest_fielder(
         if let Some(found) = fielder_distances.first() {
             *found
         } else {
-            // Still no one can field the ball. Get a player to the ball at the wall
-            let ball_distance =
-                if event.location.direction.0 < 20.0 || 70.0 < event.location.direction.0 {
-                    325.0
-                } else if event.location.direction.0 < 30.0 || 60.0 < event.location.direction.0 {
-                    // Alleys
-                    425.0
-                } else {
-                    400.0
-                };
+            // Still no one can field the ball. Get a player to it as it comes back off the wall
             let ball_location = Location {
                 direction: event.location.direction,
-                distance: Distance(ball_distance),
+                distance: Distance(
+                    ballpark.fence_distance(event.location.direction).0 - levels::WALL_CAROM,
+                ),
             };
             let ball_vector = Cartesian::from(ball_location);
 
@@ -561,18 +1134,21 @@ fn closest_fielder(
                 .map(|fielder| {
                     let player = fielding_team.player_at_position(&fielder);
                     let player_speed = decider.roll_std_dev_skill_stat(
+                        Draw::FielderSpeed,
                         *levels::FIELDER_SPEED,
                         player.fielder_run_speed_bias,
                     );
-                    let player_to_ball = ball_vector.sub(fielder.starting_location().into());
+                    let player_to_ball = ball_vector.sub(alignment.location(&fielder).into());
                     let player_run_distance = player_to_ball.magnitude();
 
                     let reaction_time = decider.roll_std_dev_skill_stat(
+                        Draw::PlayerReactionTime,
                         *levels::PLAYER_REACTION_TIME,
                         player.fielder_reaction_time_bias,
                     );
-                    let distance = (ball_vector - fielder.starting_location().into()).magnitude();
+                    let distance = (ball_vector - alignment.location(&fielder).into()).magnitude();
                     let fielder_transfer = decider.roll_std_dev_skill_stat(
+                        Draw::FielderTransferTime,
                         *levels::FIELDER_TRANSFER_TIME,
                         player.fielder_transfer_time_bias,
                     );
@@ -598,6 +1174,9 @@ fn closest_fielder(
     }
 }
 
+/// Height the ball comes off the bat, in feet
+const PLAYER_HEIGHT: f64 = 6.0;
+
 struct HitLocations {
     /// FieldingEvent to the point when the ball is catchable
     /// TravelTime and location.distance will both be 0.0 if the ball is always catchable off of the bat
@@ -608,105 +1187,31 @@ struct HitLocations {
     pub landed_path: FieldingEvent,
 }
 
-fn ball_path(
-    direction: HitDirection,
-    launch_angle: LaunchAngle,
-    exit_speed: Speed,
-) -> HitLocations {
-    let player_height = 6.0_f64;
+fn ball_path(trajectory: &Trajectory) -> HitLocations {
     let catch_height = 8.0_f64;
 
-    let catchable_path = if let Some([_, travel_time]) =
-        travel_time_for_ball_height(launch_angle, exit_speed, player_height, catch_height)
-    {
-        if travel_time.0 < 0.0 {
-            FieldingEvent {
-                location: Location {
-                    direction,
-                    distance: Distance(0.0),
-                },
-                travel_time: TravelTime(0.0),
-            }
-        } else {
-            FieldingEvent {
-                location: Location {
-                    direction,
-                    distance: Distance(
-                        launch_angle.0.to_radians().cos() * exit_speed.0 * travel_time.0,
-                    ),
-                },
-                travel_time,
-            }
-        }
-    } else {
-        FieldingEvent {
+    // Catchable all the way if it never gets over a fielder's reach
+    let catchable_path = trajectory
+        .descending_through(catch_height)
+        .unwrap_or(FieldingEvent {
             location: Location {
-                direction,
+                direction: trajectory.landing().location.direction,
                 distance: Distance(0.0),
             },
             travel_time: TravelTime(0.0),
-        }
-    };
-
-    let [_, landed_travel_time] =
-        travel_time_for_ball_height(launch_angle, exit_speed, player_height, 0.0)
-            .expect("Ball somehow never hits the ground.");
-    let landed_distance =
-        Distance(launch_angle.0.to_radians().cos() * exit_speed.0 * landed_travel_time.0);
-
-    let landed_path = FieldingEvent {
-        location: Location {
-            direction,
-            distance: landed_distance,
-        },
-        travel_time: TravelTime(landed_travel_time.0),
-    };
+        });
 
     HitLocations {
         catchable_path,
-        landed_path,
-    }
-}
-
-fn travel_time_for_ball_height(
-    launch_angle: LaunchAngle,
-    exit_speed: Speed,
-    initial_height: f64,
-    travel_time_height: f64,
-) -> Option<[TravelTime; 2]> {
-    let gravity = -32.174_f64;
-    let launch_angle = launch_angle.0;
-    let exit_speed = exit_speed.0;
-
-    let angle_sin = launch_angle.to_radians().sin();
-    let determinant = angle_sin.powf(2.0) * exit_speed.powf(2.0)
-        - 2.0 * gravity * (initial_height - travel_time_height);
-
-    if determinant < 0.0 {
-        None
-    } else {
-        let first_numerator = -angle_sin * exit_speed - determinant.sqrt();
-        let second_numerator = -angle_sin * exit_speed + determinant.sqrt();
-        let first = first_numerator / gravity;
-        let second = second_numerator / gravity;
-
-        let (lesser, greater) = if first < second {
-            (first, second)
-        } else {
-            (second, first)
-        };
-
-        let lesser_travel_time = TravelTime(lesser);
-        let greater_travel_time = TravelTime(greater);
-        Some([lesser_travel_time, greater_travel_time])
+        landed_path: trajectory.landing(),
     }
 }
 
 fn player_distance_to_catch_hit(
-    fielder: &Fielder,
+    fielder_location: Location,
     hit_locations: &HitLocations,
 ) -> (Location, Distance) {
-    let player_point = Cartesian::from(fielder.starting_location());
+    let player_point = Cartesian::from(fielder_location);
     let catchable_point = Cartesian::from(hit_locations.catchable_path.location);
     let landed_point = Cartesian::from(hit_locations.landed_path.location);
 
@@ -0,0 +1,195 @@
+use crate::{
+    fielding::{FieldingEvent, TravelTime},
+    prelude::*,
+};
+
+const GRAVITY: f64 = 32.174;
+/// In feet
+const BALL_RADIUS: f64 = 0.1208;
+/// In pounds
+const BALL_MASS: f64 = 0.3203;
+/// Seconds between integration steps
+const TIME_STEP: f64 = 0.01;
+/// Stops integrating a ball that somehow never comes down
+const MAX_FLIGHT_TIME: f64 = 20.0;
+
+/// Feet along the hit direction, feet up, and feet toward the first base side
+type Vector = [f64; 3];
+
+fn add(lhs: Vector, rhs: Vector) -> Vector {
+    [lhs[0] + rhs[0], lhs[1] + rhs[1], lhs[2] + rhs[2]]
+}
+
+fn scale(vector: Vector, by: f64) -> Vector {
+    vector.map(|component| component * by)
+}
+
+fn cross(lhs: Vector, rhs: Vector) -> Vector {
+    [
+        lhs[1] * rhs[2] - lhs[2] * rhs[1],
+        lhs[2] * rhs[0] - lhs[0] * rhs[2],
+        lhs[0] * rhs[1] - lhs[1] * rhs[0],
+    ]
+}
+
+fn magnitude(vector: Vector) -> f64 {
+    vector
+        .iter()
+        .map(|component| component * component)
+        .sum::<f64>()
+        .sqrt()
+}
+
+#[derive(Clone, Copy)]
+struct FlightPoint {
+    time: f64,
+    position: Vector,
+}
+
+/// A batted ball's flight from off the bat until it lands, integrated with
+/// drag and lift from its spin
+pub(crate) struct Trajectory {
+    direction: HitDirection,
+    points: Vec<FlightPoint>,
+}
+
+impl Trajectory {
+    pub(crate) fn new(
+        direction: HitDirection,
+        launch_angle: LaunchAngle,
+        exit_speed: Speed,
+        spin: Spin,
+        initial_height: f64,
+        ballpark: &Ballpark,
+        conditions: &GameConditions,
+    ) -> Self {
+        let angle = launch_angle.0.to_radians();
+        let drag_factor =
+            conditions.air_density(ballpark) * std::f64::consts::PI * BALL_RADIUS.powi(2)
+                / (2.0 * BALL_MASS);
+        let spin = spin.angular_velocity();
+
+        // The wind across the ball's line of flight
+        let wind_angle = (conditions.wind_direction.0 - direction.0).to_radians();
+        let wind_speed = conditions.wind_speed().0;
+        let wind = [wind_speed * wind_angle.cos(), 0.0, wind_speed * wind_angle.sin()];
+
+        let mut position = [0.0, initial_height, 0.0];
+        let mut velocity = [exit_speed.0 * angle.cos(), exit_speed.0 * angle.sin(), 0.0];
+        let mut time = 0.0;
+        let mut points = vec![FlightPoint { time, position }];
+
+        // Heun's method, averaging the acceleration at each end of the step
+        while position[1] > 0.0 && time < MAX_FLIGHT_TIME {
+            let current = acceleration(velocity, wind, spin, drag_factor);
+            let predicted = add(velocity, scale(current, TIME_STEP));
+            let predicted_acceleration = acceleration(predicted, wind, spin, drag_factor);
+
+            position = add(position, scale(add(velocity, predicted), TIME_STEP / 2.0));
+            velocity = add(
+                velocity,
+                scale(add(current, predicted_acceleration), TIME_STEP / 2.0),
+            );
+            time += TIME_STEP;
+            points.push(FlightPoint { time, position });
+        }
+
+        // Back up the last step to where it met the ground
+        if let [.., above, below] = points[..] {
+            if below.position[1] < 0.0 {
+                let t = above.position[1] / (above.position[1] - below.position[1]);
+                points.pop();
+                points.push(interpolate(above, below, t));
+            }
+        }
+
+        Self { direction, points }
+    }
+
+    /// Where and when the ball hits the ground
+    pub(crate) fn landing(&self) -> FieldingEvent {
+        self.event(*self.points.last().expect("Trajectory has no points"))
+    }
+
+    /// Where and when the ball comes back down through `height` on its way
+    /// to the ground, or `None` if it never gets that high
+    pub(crate) fn descending_through(&self, height: f64) -> Option<FieldingEvent> {
+        let index = self
+            .points
+            .windows(2)
+            .rposition(|pair| pair[0].position[1] >= height && pair[1].position[1] < height)?;
+        let [above, below] = [self.points[index], self.points[index + 1]];
+        let t = (above.position[1] - height) / (above.position[1] - below.position[1]);
+
+        Some(self.event(interpolate(above, below, t)))
+    }
+
+    /// Height of the ball when it has travelled `distance` from the plate,
+    /// or `None` if it lands short
+    pub(crate) fn height_at_distance(&self, distance: Distance) -> Option<f64> {
+        let index = self
+            .points
+            .iter()
+            .position(|point| horizontal_distance(point.position) >= distance.0)?;
+        if index == 0 {
+            return Some(self.points[0].position[1]);
+        }
+
+        let [before, after] = [self.points[index - 1], self.points[index]];
+        let before_distance = horizontal_distance(before.position);
+        let t = (distance.0 - before_distance)
+            / (horizontal_distance(after.position) - before_distance);
+
+        Some(interpolate(before, after, t).position[1])
+    }
+
+    fn event(&self, point: FlightPoint) -> FieldingEvent {
+        let [downrange, _, lateral] = point.position;
+
+        FieldingEvent {
+            location: Location {
+                direction: HitDirection(
+                    self.direction.0 + lateral.atan2(downrange.max(0.0)).to_degrees(),
+                ),
+                distance: Distance(horizontal_distance(point.position)),
+            },
+            travel_time: TravelTime(point.time),
+        }
+    }
+}
+
+fn horizontal_distance(position: Vector) -> f64 {
+    position[0].hypot(position[2])
+}
+
+fn interpolate(from: FlightPoint, to: FlightPoint, t: f64) -> FlightPoint {
+    FlightPoint {
+        time: from.time + (to.time - from.time) * t,
+        position: add(
+            from.position,
+            scale(add(to.position, scale(from.position, -1.0)), t),
+        ),
+    }
+}
+
+/// Gravity, drag against the direction of flight through the air, and lift
+/// square to both the spin axis and the direction of flight
+fn acceleration(velocity: Vector, wind: Vector, spin: Vector, drag_factor: f64) -> Vector {
+    let velocity = add(velocity, scale(wind, -1.0));
+    let speed = magnitude(velocity);
+    let spin_rate = magnitude(spin);
+
+    let drag = scale(velocity, -drag_factor * levels::DRAG_COEFFICIENT * speed);
+    let lift = if spin_rate > 0.0 && speed > 0.0 {
+        let spin_factor = BALL_RADIUS * spin_rate / speed;
+        let lift_coefficient = spin_factor / (0.4 + 2.32 * spin_factor);
+        scale(
+            cross(spin, velocity),
+            drag_factor * lift_coefficient * speed / spin_rate,
+        )
+    } else {
+        [0.0; 3]
+    };
+
+    add(add(drag, lift), [0.0, -GRAVITY, 0.0])
+}
@@ -2,7 +2,7 @@
 use serde::{Deserialize, Serialize};
 use ts_rs::TS;
 
-use crate::{inning::simulate_inning, prelude::*};
+use crate::prelude::*;
 
 #[derive(Debug, Clone, TS)]
 #[ts(export)]
@@ -12,6 +12,9 @@ pub struct GameRecord {
     pub outcome: GameOutcome,
     pub away_team: Team,
     pub home_team: Team,
+    pub rules: Rules,
+    pub ballpark: Ballpark,
+    pub conditions: GameConditions,
 }
 
 #[derive(Debug, Clone, TS)]
@@ -20,94 +23,62 @@ pub struct GameRecord {
 pub struct GameOutcome {
     pub home_score: Score,
     pub home_hits: u16,
+    /// Made by the home team in the field
+    pub home_errors: u16,
     pub away_score: Score,
     pub away_hits: u16,
+    pub away_errors: u16,
+    pub end_reason: GameEndReason,
 }
 
-pub type GameProgress = GameOutcome;
+#[derive(Default, Debug, Clone, TS)]
+#[ts(export)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct GameProgress {
+    pub home_score: Score,
+    pub home_hits: u16,
+    /// Made by the home team in the field
+    pub home_errors: u16,
+    pub away_score: Score,
+    pub away_hits: u16,
+    pub away_errors: u16,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, TS)]
+#[ts(export)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum GameEndReason {
+    /// Played out to a winner, through extra innings if needed
+    Regulation,
+    /// The home team took the lead in its final half inning
+    WalkOff,
+    /// Ended early by `Rules::mercy_rule`
+    Mercy,
+    /// Still tied after `Rules::max_innings`
+    Tie,
+    /// A half inning hit `Rules::max_at_bats_per_half_inning`
+    AtBatCap,
+}
 
 pub fn simulate_game(decider: &mut impl Decider) -> GameRecord {
-    simulate_game_with_teams(decider, &Default::default(), &Default::default())
+    simulate_game_with_teams(
+        decider,
+        &Default::default(),
+        &Default::default(),
+        &Default::default(),
+        &Default::default(),
+        &Default::default(),
+    )
+    .expect("Default rules are playable")
 }
 
 pub fn simulate_game_with_teams(
     decider: &mut impl Decider,
     home_team: &Team,
     away_team: &Team,
-) -> GameRecord {
-    let mut away_score: Score = 0;
-    let mut away_hits: u16 = 0;
-    let mut away_batting_index: u8 = 0;
-
-    let mut home_score: Score = 0;
-    let mut home_hits: u16 = 0;
-    let mut home_batting_index: u8 = 0;
-
-    let mut running_innings = Vec::<(InningRecord, GameProgress)>::new();
-
-    for _ in 0..Consts::COUNT_INNINGS {
-        let inning = simulate_inning(
-            away_batting_index,
-            &away_team,
-            home_batting_index,
-            &home_team,
-            decider,
-        );
-
-        away_score += inning.outcome.away.runs_scored;
-        home_score += inning.outcome.home.runs_scored;
-        away_hits += inning.outcome.away.total_hits as u16;
-        home_hits += inning.outcome.home.total_hits as u16;
-
-        away_batting_index =
-            (away_batting_index + inning.away.at_bats.len() as u8) % Consts::PLAYERS_PER_LINEUP;
-        home_batting_index =
-            (home_batting_index + inning.home.at_bats.len() as u8) % Consts::PLAYERS_PER_LINEUP;
-
-        let progress = GameProgress {
-            away_score,
-            away_hits,
-            home_score,
-            home_hits,
-        };
-        running_innings.push((inning, progress));
-    }
-
-    while away_score == home_score {
-        let inning = simulate_inning(
-            away_batting_index,
-            &away_team,
-            home_batting_index,
-            &home_team,
-            decider,
-        );
-        away_score += inning.outcome.away.runs_scored;
-        home_score += inning.outcome.home.runs_scored;
-        away_hits += inning.outcome.away.total_hits as u16;
-        home_hits += inning.outcome.home.total_hits as u16;
-
-        away_batting_index =
-            (away_batting_index + inning.away.at_bats.len() as u8) % Consts::PLAYERS_PER_LINEUP;
-        home_batting_index =
-            (home_batting_index + inning.home.at_bats.len() as u8) % Consts::PLAYERS_PER_LINEUP;
-        let progress = GameProgress {
-            away_score,
-            away_hits,
-            home_score,
-            home_hits,
-        };
-        running_innings.push((inning, progress));
-    }
-
-    GameRecord {
-        innings: running_innings.into_boxed_slice(),
-        outcome: GameOutcome {
-            home_score,
-            home_hits,
-            away_score,
-            away_hits,
-        },
-        away_team: away_team.clone(),
-        home_team: home_team.clone(),
-    }
+    rules: &Rules,
+    ballpark: &Ballpark,
+    conditions: &GameConditions,
+) -> Result<GameRecord, RulesError> {
+    Ok(GameSimulation::new(decider, home_team, away_team, rules, ballpark, conditions)?.finish())
 }
@@ -2,12 +2,14 @@
 use serde::{Deserialize, Serialize};
 use ts_rs::TS;
 
-use crate::{at_bat::simulate_at_bat, prelude::*};
+use crate::{at_bat::AtBatSimulation, prelude::*};
 
 #[derive(Clone, Debug, TS)]
 #[ts(export)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct HalfInningRecord {
+    /// Runners placed on base before the first pitch, e.g. the extra innings runner
+    pub starting_bases: [Option<u8>; 3],
     pub at_bats: Box<[(AtBatRecord, HalfInningProgress)]>,
     pub outcome: HalfInningOutcome,
 }
@@ -27,118 +29,373 @@ pub struct HalfInningProgress {
 pub struct HalfInningOutcome {
     pub runs_scored: Score,
     pub total_hits: u8,
+    /// Made by the fielding team
+    pub errors: u8,
+    /// Runs that would not have scored without an error, counted out of `runs_scored`
+    pub unearned_runs: Score,
+    /// Ended by `Rules::max_at_bats_per_half_inning` rather than outs or a walk-off
+    pub reached_at_bat_cap: bool,
 }
 
-const MAX_AT_BATS_PER_INNING: usize = 27;
+/// Ends early once `runs_to_win` runs are in, cutting short the at bat if they score mid count
+#[allow(clippy::too_many_arguments)]
 pub fn simulate_half_inning(
     starting_index: u8,
     batting_team: &Team,
     fielding_team: &Team,
+    starting_bases: [Option<u8>; 3],
+    runs_to_win: Option<Score>,
+    inning_index: usize,
+    rules: &Rules,
+    ballpark: &Ballpark,
+    conditions: &GameConditions,
     decider: &mut impl Decider,
 ) -> HalfInningRecord {
-    let mut state = HalfInningState::new();
-    let mut at_bats = Vec::<(AtBatRecord, HalfInningProgress)>::new();
-
-    let mut batting_index = starting_index;
-    while state.outs_remaining > 0 && at_bats.len() < MAX_AT_BATS_PER_INNING {
-        let at_bat_record = simulate_at_bat(
-            batting_index,
-            batting_team,
-            fielding_team,
-            decider,
-            &state.bases,
-        );
+    let mut half_inning = HalfInningSimulation::new(
+        starting_index,
+        starting_bases,
+        runs_to_win,
+        inning_index,
+        rules,
+        ballpark,
+        conditions,
+    );
+    while !half_inning.is_over() {
+        half_inning.step_pitch(batting_team, fielding_team, decider);
+    }
+
+    half_inning.into_record()
+}
+
+/// What a single pitch of a half inning produced
+#[derive(Clone, Debug)]
+pub struct PitchStep {
+    /// `None` when the batter was walked intentionally instead
+    pub pitch: Option<PitchRecord>,
+    pub at_bat_progress: AtBatProgress,
+    /// Set when the pitch ended the at bat
+    pub finished_at_bat: Option<(AtBatRecord, HalfInningProgress)>,
+}
+
+/// A half inning in progress, simulated one pitch at a time
+pub struct HalfInningSimulation {
+    rules: Rules,
+    ballpark: Ballpark,
+    conditions: GameConditions,
+    batting_index: u8,
+    starting_bases: [Option<u8>; 3],
+    runs_to_win: Option<Score>,
+    /// Zero based
+    inning_index: usize,
+    state: HalfInningState,
+    at_bats: Vec<(AtBatRecord, HalfInningProgress)>,
+    at_bat: Option<AtBatSimulation>,
+}
+
+impl HalfInningSimulation {
+    pub fn new(
+        starting_index: u8,
+        starting_bases: [Option<u8>; 3],
+        runs_to_win: Option<Score>,
+        inning_index: usize,
+        rules: &Rules,
+        ballpark: &Ballpark,
+        conditions: &GameConditions,
+    ) -> Self {
+        Self {
+            rules: *rules,
+            ballpark: *ballpark,
+            conditions: *conditions,
+            batting_index: starting_index,
+            starting_bases,
+            runs_to_win,
+            inning_index,
+            state: HalfInningState::new(rules, starting_bases),
+            at_bats: Vec::new(),
+            at_bat: None,
+        }
+    }
+
+    /// Throws the next pitch, starting a new at bat if needed. Also returns
+    /// the at bat if the pitch ended it.
+    pub fn step_pitch(
+        &mut self,
+        batting_team: &Team,
+        fielding_team: &Team,
+        decider: &mut impl Decider,
+    ) -> PitchStep {
+        let at_bat = self.at_bat.get_or_insert_with(|| {
+            AtBatSimulation::new(
+                self.batting_index,
+                &self.rules,
+                &self.ballpark,
+                &self.conditions,
+                &self.state.bases,
+                self.state.number_of_outs(),
+                self.inning_index,
+            )
+        });
+        let (pitch, at_bat_progress) = at_bat.step_pitch(batting_team, fielding_team, decider);
+        let pitch_outcome = pitch.as_ref().map(|pitch| &pitch.outcome);
+        if let Some(PitchOutcome::WildPitch | PitchOutcome::PassedBall) = pitch_outcome {
+            // Runs that score on a passed ball are unearned, as on an error
+            let unearned = pitch_outcome == Some(&PitchOutcome::PassedBall);
+            self.state.advance_on_pitch(*at_bat.base_state(), unearned);
+            // The winning run can score before the batter finishes
+            if self.state.has_reached(self.runs_to_win) {
+                at_bat.cut_short();
+            }
+        }
+        if !at_bat.is_over() {
+            return PitchStep {
+                pitch,
+                at_bat_progress,
+                finished_at_bat: None,
+            };
+        }
 
+        let at_bat_record = self
+            .at_bat
+            .take()
+            .expect("At bat finished but is missing")
+            .into_record();
         match at_bat_record.outcome.outcome_type {
-            AtBatOutcomeType::Hit(ref hit_record) => state.hit(&hit_record),
-            AtBatOutcomeType::Walk => state.walk(batting_index),
-            AtBatOutcomeType::Out => state.out(),
+            AtBatOutcomeType::Hit(ref hit_record) => {
+                self.state.hit(hit_record, self.batting_index)
+            }
+            AtBatOutcomeType::Walk
+            | AtBatOutcomeType::IntentionalWalk
+            | AtBatOutcomeType::HitByPitch => self.state.walk(self.batting_index),
+            AtBatOutcomeType::CatcherInterference => {
+                self.state.catcher_interference(self.batting_index)
+            }
+            AtBatOutcomeType::DroppedThirdStrike(ref record) if record.reached_base => {
+                self.state.walk(self.batting_index)
+            }
+            AtBatOutcomeType::DroppedThirdStrike(_) => self.state.out(),
+            AtBatOutcomeType::Out => self.state.out(),
+            AtBatOutcomeType::Unfinished => {}
         };
 
-        let progress = HalfInningProgress {
-            bases: state.bases.map(|base| base.is_some()),
-            score_change: state.runs_scored,
-            outs: state.number_of_outs(),
-        };
+        let at_bat = (at_bat_record, self.progress());
+        self.at_bats.push(at_bat.clone());
+        self.batting_index = (self.batting_index + 1) % self.rules.players_per_lineup;
+
+        PitchStep {
+            pitch,
+            at_bat_progress,
+            finished_at_bat: Some(at_bat),
+        }
+    }
 
-        at_bats.push((at_bat_record, progress));
-        batting_index = (batting_index + 1) % Consts::PLAYERS_PER_LINEUP;
+    pub fn progress(&self) -> HalfInningProgress {
+        HalfInningProgress {
+            bases: self.state.bases.map(|base| base.is_some()),
+            score_change: self.state.runs_scored,
+            outs: self.state.number_of_outs(),
+        }
     }
 
-    let outcome = HalfInningOutcome {
-        runs_scored: state.runs_scored,
-        total_hits: state.total_hits,
-    };
+    pub fn total_hits(&self) -> u8 {
+        self.state.total_hits
+    }
 
-    HalfInningRecord {
-        at_bats: at_bats.into_boxed_slice(),
-        outcome,
+    /// Errors made by the fielding team so far
+    pub fn errors(&self) -> u8 {
+        self.state.errors
+    }
+
+    /// Count of the at bat in progress, if one has started
+    pub fn at_bat_progress(&self) -> Option<AtBatProgress> {
+        self.at_bat.as_ref().map(AtBatSimulation::progress)
+    }
+
+    /// Lineup index of the batter up next, or currently at the plate
+    pub fn batting_index(&self) -> u8 {
+        self.batting_index
+    }
+
+    pub fn is_over(&self) -> bool {
+        self.at_bat.is_none()
+            && (self.state.outs_remaining == 0
+                || self.at_bats.len() >= self.rules.max_at_bats_per_half_inning as usize
+                || self.has_won())
+    }
+
+    pub fn into_record(self) -> HalfInningRecord {
+        let outcome = HalfInningOutcome {
+            runs_scored: self.state.runs_scored,
+            total_hits: self.state.total_hits,
+            errors: self.state.errors,
+            unearned_runs: self.state.unearned_runs,
+            reached_at_bat_cap: self.state.outs_remaining > 0 && !self.has_won(),
+        };
+
+        HalfInningRecord {
+            starting_bases: self.starting_bases,
+            at_bats: self.at_bats.into_boxed_slice(),
+            outcome,
+        }
+    }
+
+    fn has_won(&self) -> bool {
+        self.state.has_reached(self.runs_to_win)
     }
 }
 
 struct HalfInningState {
+    outs_per_half_inning: u8,
     bases: [Option<u8>; 3],
     outs_remaining: u8,
     runs_scored: Score,
     total_hits: u8,
+    errors: u8,
+    unearned_runs: Score,
+    /// Runners on base only because of an error, whose runs are unearned
+    reached_on_error: Vec<u8>,
+    /// Outs the fielding team would have made without its errors
+    outs_saved_by_errors: u8,
 }
 
 impl HalfInningState {
-    fn new() -> Self {
+    fn new(rules: &Rules, bases: [Option<u8>; 3]) -> Self {
         Self {
-            bases: [None, None, None],
-            outs_remaining: Consts::OUTS_PER_HALF_INNING,
+            outs_per_half_inning: rules.outs_per_half_inning,
+            bases,
+            outs_remaining: rules.outs_per_half_inning,
             runs_scored: 0,
             total_hits: 0,
+            errors: 0,
+            unearned_runs: 0,
+            reached_on_error: Vec::new(),
+            outs_saved_by_errors: 0,
         }
     }
 
     fn number_of_outs(&self) -> u8 {
-        Consts::OUTS_PER_HALF_INNING - self.outs_remaining
+        self.outs_per_half_inning - self.outs_remaining
+    }
+
+    fn has_reached(&self, runs_to_win: Option<Score>) -> bool {
+        runs_to_win.is_some_and(|runs| self.runs_scored >= runs)
     }
 
     fn out(&mut self) {
         self.outs_remaining -= 1;
     }
 
-    fn hit(&mut self, hit_record: &HitRecord) {
+    fn hit(&mut self, hit_record: &HitRecord, batter_lineup_index: u8) {
         match &hit_record.outcome {
-            HitOutcome::HomeRun => self.home_run(),
+            HitOutcome::HomeRun => self.home_run(batter_lineup_index),
             HitOutcome::InPlay(fielding) => {
-                let fielding_record = &fielding;
-                self.total_hits += match fielding_record.landing {
-                    BallLanding::Out(_, _) => 0,
-                    BallLanding::Landed(_, _) => 1,
-                };
-
                 let play_outcome = &fielding.base_running_record.outcome;
+                if play_outcome.batter_hit_type.is_hit() {
+                    self.total_hits += 1;
+                }
+
+                let scorers = self.scorers(&fielding.base_running_record, batter_lineup_index);
+                self.score(&scorers, play_outcome.unearned_runs);
+
+                if fielding.error.is_some() {
+                    self.errors += 1;
+                }
+                if play_outcome.batter_hit_type == HitType::ReachedOnError {
+                    self.reached_on_error.push(batter_lineup_index);
+                    self.outs_saved_by_errors += 1;
+                }
+
                 self.outs_remaining = self.outs_remaining.saturating_sub(play_outcome.outs_made);
-                self.runs_scored += play_outcome.runs_scored as u16;
                 self.bases = play_outcome.ending_base_state;
+                self.reached_on_error.retain(|runner| self.bases.contains(&Some(*runner)));
             }
         }
     }
 
-    fn walk(&mut self, batter_lineup_index: u8) {
-        self.runs_scored += if self.bases.iter().all(|on| on.is_some()) {
-            1
+    /// Runners and the batter that crossed the plate on the play. Catches
+    /// record no movement for the batter, so the batter only scored if the
+    /// runners on base do not account for every run.
+    fn scorers(&self, record: &BaseRunningRecord, batter_lineup_index: u8) -> Vec<u8> {
+        let put_out: Vec<usize> = record
+            .movements
+            .iter()
+            .filter(|movement| matches!(movement.bases_moved, MoveType::Out(_)))
+            .filter_map(|movement| movement.starting_base)
+            .collect();
+        let ending_base_state = record.outcome.ending_base_state;
+
+        let mut scorers: Vec<u8> = [Consts::THIRD, Consts::SECOND, Consts::FIRST]
+            .into_iter()
+            .filter(|base| !put_out.contains(base))
+            .filter_map(|base| self.bases[base])
+            .filter(|runner| !ending_base_state.contains(&Some(*runner)))
+            .chain([batter_lineup_index])
+            .collect();
+        scorers.truncate(record.outcome.runs_scored as usize);
+
+        scorers
+    }
+
+    /// Counts the runs, charging as unearned those by runners who reached on
+    /// an error, those the play only scored by an error, and every run once the
+    /// fielding team would have been out of the inning without its errors
+    fn score(&mut self, scorers: &[u8], unearned_on_play: u8) {
+        let runs = scorers.len() as Score;
+        let unearned = if self.number_of_outs() + self.outs_saved_by_errors
+            >= self.outs_per_half_inning
+        {
+            runs
         } else {
-            0
+            let reached_on_error = scorers
+                .iter()
+                .filter(|runner| self.reached_on_error.contains(runner))
+                .count() as Score;
+            runs.min(reached_on_error + unearned_on_play as Score)
         };
+
+        self.runs_scored += runs;
+        self.unearned_runs += unearned;
+    }
+
+    /// Takes the bases from the at bat once a wild pitch or passed ball has
+    /// moved every runner up, scoring the runner who was on third
+    fn advance_on_pitch(&mut self, bases: [Option<u8>; 3], unearned: bool) {
+        let scorer = self.bases[Consts::THIRD];
+        self.bases = bases;
+        self.score(scorer.as_slice(), unearned as u8);
+        self.reached_on_error.retain(|runner| self.bases.contains(&Some(*runner)));
+    }
+
+    fn walk(&mut self, batter_lineup_index: u8) {
+        if self.bases.iter().all(|on| on.is_some()) {
+            let scorer = self.bases[Consts::THIRD];
+            self.score(scorer.as_slice(), 0);
+        }
         self.bases[Consts::THIRD] = if self.bases.iter().take(2).all(|on| on.is_some()) {
             self.bases[Consts::SECOND]
         } else {
             self.bases[Consts::THIRD]
         };
-        self.bases[Consts::SECOND] =
-            if self.bases.iter().take(1).all(|on| on.is_some()) { self.bases[Consts::FIRST] } else { self.bases[Consts::SECOND] };
-
+        self.bases[Consts::SECOND] = if self.bases.iter().take(1).all(|on| on.is_some()) {
+            self.bases[Consts::FIRST]
+        } else {
+            self.bases[Consts::SECOND]
+        };
         self.bases[Consts::FIRST] = Some(batter_lineup_index);
     }
 
-    fn home_run(&mut self) {
+    /// An error charged to the catcher, so the batter reached on it
+    fn catcher_interference(&mut self, batter_lineup_index: u8) {
+        self.walk(batter_lineup_index);
+        self.errors += 1;
+        self.reached_on_error.push(batter_lineup_index);
+    }
+
+    fn home_run(&mut self, batter_lineup_index: u8) {
         self.total_hits += 1;
-        self.runs_scored += self.bases.iter().filter(|b| b.is_some()).count() as u16 + 1;
+        let scorers: Vec<u8> =
+            self.bases.iter().flatten().copied().chain([batter_lineup_index]).collect();
+        self.score(&scorers, 0);
         self.bases = [None, None, None];
+        self.reached_on_error.clear();
     }
 }
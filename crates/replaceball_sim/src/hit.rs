@@ -2,7 +2,7 @@
 use serde::{Deserialize, Serialize};
 use ts_rs::TS;
 
-use crate::{fielding, prelude::*};
+use crate::{base_running, fielding, prelude::*};
 
 #[derive(Clone, Copy, Debug, PartialEq, TS)]
 #[ts(export)]
@@ -10,18 +10,10 @@ use crate::{fielding, prelude::*};
 pub struct LaunchAngle(pub f64);
 
 impl LaunchAngle {
-    const BIAS_MAX_MULT: f64 = 2.0;
-
+    /// Shifts rather than scales the average, as the average launch angle is
+    /// zero and scaling it would leave the bias with no effect
     fn from_decider(decider: &mut impl Decider, bias: i8) -> Self {
-        let bias_positive = bias as i16 - std::i8::MIN as i16 + 1;
-        Self(decider.roll_stat(
-            *levels::HIT_LAUNCH_ANGLE,
-            Skill {
-                average_multiplier: Self::BIAS_MAX_MULT
-                    * (bias_positive as f64 / std::u8::MAX as f64),
-                ..Default::default()
-            },
-        ))
+        Self(decider.roll_std_dev_skill_stat(Draw::HitLaunchAngle, *levels::HIT_LAUNCH_ANGLE, bias))
     }
 }
 
@@ -31,6 +23,51 @@ impl LaunchAngle {
 /// Everything is in feet per second
 pub struct Speed(pub f64);
 
+/// Spin off the bat, in revolutions per minute
+#[derive(Clone, Copy, Debug, Default, PartialEq, TS)]
+#[ts(export)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Spin {
+    /// Holds the ball up. Negative is topspin, which dives it into the ground.
+    pub backspin: f64,
+    /// Curves the ball toward the first base line, or the third base line if negative
+    pub sidespin: f64,
+}
+
+impl Spin {
+    /// Backspin climbs with the launch angle, and balls hooked or sliced
+    /// toward the nearer line keep curving that way. Foul directions are
+    /// folded back about the foul lines first, so a ball fouled straight back
+    /// spins like one hit up the middle.
+    fn from_decider(
+        decider: &mut impl Decider,
+        direction: HitDirection,
+        launch_angle: LaunchAngle,
+    ) -> Self {
+        let backspin = levels::BACKSPIN_AT_ZERO_LAUNCH
+            + levels::BACKSPIN_PER_LAUNCH_DEGREE * launch_angle.0
+            + decider.roll_std_dev_skill_stat(Draw::HitBackspin, *levels::HIT_SPIN_SPREAD, 0);
+        let folded_direction = match direction.0.rem_euclid(180.0) {
+            past_line if past_line > 90.0 => 180.0 - past_line,
+            fair => fair,
+        };
+        let sidespin = levels::SIDESPIN_TOWARD_LINE * (folded_direction - 45.0) / 45.0
+            + decider.roll_std_dev_skill_stat(Draw::HitSidespin, *levels::HIT_SPIN_SPREAD, 0);
+
+        Self { backspin, sidespin }
+    }
+
+    /// In radians per second, about the axes of the ball's flight
+    pub(crate) fn angular_velocity(&self) -> [f64; 3] {
+        let radians_per_revolution = std::f64::consts::TAU / 60.0;
+        [
+            0.0,
+            -self.sidespin * radians_per_revolution,
+            self.backspin * radians_per_revolution,
+        ]
+    }
+}
+
 #[derive(Clone, Debug, PartialEq, TS)]
 #[ts(export)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
@@ -38,6 +75,7 @@ pub struct HitRecord {
     pub direction: HitDirection,
     pub launch_angle: LaunchAngle,
     pub exit_speed: Speed,
+    pub spin: Spin,
 
     pub outcome: HitOutcome,
 }
@@ -72,22 +110,45 @@ pub enum HitType {
     Double,
     Triple,
     HomeRun,
+    /// Bunted out at first, moving a runner up with fewer than two outs
+    Sacrifice,
+    /// Safe on a misplay that should have been an out, which is not a hit
+    ReachedOnError,
+}
+
+impl HitType {
+    pub fn is_hit(&self) -> bool {
+        matches!(
+            self,
+            HitType::Single | HitType::Double | HitType::Triple | HitType::HomeRun
+        )
+    }
 }
 
+/// Returns `None` when the ball curves foul and isn't caught, leaving the at
+/// bat to count it
+#[allow(clippy::too_many_arguments)]
 pub fn simulate_hit(
-    pitch_location: &PitchLocation,
+    pitch: &PitchRecord,
     is_ball: bool,
     batter_lineup_index: u8,
     batting_team: &Team,
     fielding_team: &Team,
+    alignment: &DefensiveAlignment,
+    ballpark: &Ballpark,
+    conditions: &GameConditions,
     decider: &mut impl Decider,
     base_state: &[Option<u8>; 3],
-) -> HitRecord {
+    runners_going: bool,
+) -> Option<HitRecord> {
     let batter = batting_team.player_at_batting_index(batter_lineup_index);
-    let pitch_width_bias = match pitch_location.width {
-        PitchWidth::Left => -42,
+    let pitch_effects = fielding_team.pitcher().repertoire_pitch(pitch.pitch_type);
+    let bats_left = batter.bats.left_against(fielding_team.pitcher().throws);
+    // Inside pitches are pulled, and outside ones go the other way
+    let pitch_width_bias = match &pitch.location.width {
         PitchWidth::Center => 0,
-        PitchWidth::Right => 42,
+        width if *width == PitchWidth::inside(bats_left) => 42,
+        _ => -42,
     };
     let direction = HitDirection::from_decider(
         decider,
@@ -96,8 +157,14 @@ pub fn simulate_hit(
             .saturating_sub(fielding_team.pitcher().pitcher_hit_direction_bias)
             .saturating_sub(pitch_width_bias),
     );
+    // Spray is rolled for a right handed batter
+    let direction = if bats_left {
+        direction.mirrored()
+    } else {
+        direction
+    };
 
-    let pitch_height_bias = match pitch_location.height {
+    let pitch_height_bias = match pitch.location.height {
         PitchHeight::High => -20,
         PitchHeight::Middle => 0,
         PitchHeight::Low => 20,
@@ -107,39 +174,179 @@ pub fn simulate_hit(
         batter
             .hitter_launch_angle_bias
             .saturating_sub(fielding_team.pitcher().pitcher_launch_angle_bias)
+            .saturating_sub(pitch_effects.launch_angle_bias)
             .saturating_sub(pitch_height_bias),
     );
 
     let is_ball_bias = if is_ball { -30 } else { 0 };
+    let platoon_hit_speed_bias = if batter.has_platoon_advantage(fielding_team.pitcher()) {
+        levels::PLATOON_HIT_SPEED_BIAS
+    } else {
+        0
+    };
     let exit_speed = decider.roll_std_dev_mult_skill_stat(
+        Draw::HitExitSpeed,
         *levels::HIT_EXIT_SPEED,
         batter
             .hitter_hit_speed_bias
             .saturating_sub(fielding_team.pitcher().pitcher_hit_speed_bias)
-            .saturating_sub(is_ball_bias),
-            1.5,
+            .saturating_sub(pitch_effects.hit_speed_bias)
+            .saturating_sub(is_ball_bias)
+            .saturating_add(platoon_hit_speed_bias),
+        1.5,
     );
 
-    // Let misses on average launch angle drop exit speed to simulate missing the "sweet spot"
-    let launch_error = clamp(
-        ((launch_angle.0 - levels::HIT_LAUNCH_ANGLE.average).abs() - 2.0) / 200.0,
-        0.0..=0.25,
+    let exit_speed = Speed(exit_speed);
+    let spin = Spin::from_decider(decider, direction, launch_angle);
+
+    let outcome = fielding::simulate_fielding(
+        direction,
+        launch_angle,
+        exit_speed,
+        spin,
+        batter_lineup_index,
+        batting_team,
+        fielding_team,
+        alignment,
+        ballpark,
+        conditions,
+        base_state,
+        runners_going,
+        decider,
+    )?;
+
+    Some(HitRecord {
+        direction,
+        launch_angle,
+        exit_speed,
+        spin,
+        outcome,
+    })
+}
+
+/// A bunt put down fair, charged by the pitcher, catcher and corner infielders
+#[allow(clippy::too_many_arguments)]
+pub fn simulate_bunt(
+    pitch: &PitchRecord,
+    batter_lineup_index: u8,
+    batting_team: &Team,
+    fielding_team: &Team,
+    alignment: &DefensiveAlignment,
+    decider: &mut impl Decider,
+    base_state: &[Option<u8>; 3],
+    outs: u8,
+    outs_per_half_inning: u8,
+    runners_going: bool,
+) -> HitRecord {
+    let batter = batting_team.player_at_batting_index(batter_lineup_index);
+
+    // Sacrifices are pushed toward the corners, bunts for a hit down the lines
+    let direction_range = match pitch.batting_call {
+        BattingCall::BuntForHit => 3.0..87.0,
+        _ => 10.0..80.0,
+    };
+    let direction = HitDirection(decider.roll_uniform(Draw::BuntDirection, direction_range));
+    // Good bunters keep it down and deaden it
+    let launch_angle = LaunchAngle(decider.roll_std_dev_skill_stat(
+        Draw::BuntLaunchAngle,
+        *levels::BUNT_LAUNCH_ANGLE,
+        batter.hitter_bunt_bias.saturating_neg(),
+    ));
+    let exit_speed = Speed(decider.roll_std_dev_skill_stat(
+        Draw::BuntExitSpeed,
+        *levels::BUNT_EXIT_SPEED,
+        batter.hitter_bunt_bias.saturating_neg(),
+    ));
+
+    let path = fielding::bunt_path(direction, launch_angle, exit_speed);
+    let mut fielding_record = fielding::simulate_bunt_fielding(
+        &path,
+        batter_lineup_index,
+        batting_team,
+        fielding_team,
+        alignment,
+        base_state,
+        runners_going,
+        decider,
     );
-    let exit_speed = Speed(exit_speed * (1.0 - launch_error));
+    // Moving a runner up with the last out counts for nothing
+    if outs + 1 < outs_per_half_inning {
+        base_running::credit_sacrifice(&mut fielding_record.base_running_record);
+    }
 
     HitRecord {
         direction,
         launch_angle,
         exit_speed,
-        outcome: fielding::simulate_fielding(
-            direction,
-            launch_angle,
-            exit_speed,
-            batter_lineup_index,
-            batting_team,
-            fielding_team,
-            base_state,
-            decider,
-        ),
+        spin: Spin::default(),
+        outcome: HitOutcome::InPlay(fielding_record),
     }
 }
+
+/// A foul ball's flight off the bat, which is only recorded if a fielder
+/// catches it for an out
+#[allow(clippy::too_many_arguments)]
+pub fn simulate_foul(
+    pitch: &PitchRecord,
+    batter_lineup_index: u8,
+    batting_team: &Team,
+    fielding_team: &Team,
+    alignment: &DefensiveAlignment,
+    ballpark: &Ballpark,
+    conditions: &GameConditions,
+    decider: &mut impl Decider,
+    base_state: &[Option<u8>; 3],
+    runners_going: bool,
+) -> Option<HitRecord> {
+    let batter = batting_team.player_at_batting_index(batter_lineup_index);
+    let pitcher = fielding_team.pitcher();
+    let pitch_effects = pitcher.repertoire_pitch(pitch.pitch_type);
+
+    let direction = HitDirection::foul_from_decider(decider);
+    let launch_angle = LaunchAngle(
+        decider.roll_std_dev_skill_stat(
+            Draw::FoulLaunchAngle,
+            *levels::FOUL_LAUNCH_ANGLE,
+            batter
+                .hitter_launch_angle_bias
+                .saturating_sub(pitcher.pitcher_launch_angle_bias)
+                .saturating_sub(pitch_effects.launch_angle_bias),
+        ),
+    );
+    let exit_speed = Speed(
+        decider.roll_std_dev_skill_stat(
+            Draw::FoulExitSpeed,
+            *levels::FOUL_EXIT_SPEED,
+            batter
+                .hitter_hit_speed_bias
+                .saturating_sub(pitcher.pitcher_hit_speed_bias)
+                .saturating_sub(pitch_effects.hit_speed_bias),
+        ),
+    );
+
+    let spin = Spin::from_decider(decider, direction, launch_angle);
+
+    let fielding_record = fielding::simulate_foul_fielding(
+        direction,
+        launch_angle,
+        exit_speed,
+        spin,
+        batter_lineup_index,
+        batting_team,
+        fielding_team,
+        alignment,
+        ballpark,
+        conditions,
+        base_state,
+        runners_going,
+        decider,
+    )?;
+
+    Some(HitRecord {
+        direction,
+        launch_angle,
+        exit_speed,
+        spin,
+        outcome: HitOutcome::InPlay(fielding_record),
+    })
+}
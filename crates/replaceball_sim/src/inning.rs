@@ -9,7 +9,8 @@ use crate::{half_inning::simulate_half_inning, prelude::*};
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct InningRecord {
     pub away: HalfInningRecord,
-    pub home: HalfInningRecord,
+    /// `None` when the home team already led and did not need to bat
+    pub home: Option<HalfInningRecord>,
     pub outcome: InningOutcome,
 }
 
@@ -18,21 +19,91 @@ pub struct InningRecord {
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct InningOutcome {
     pub away: HalfInningOutcome,
-    pub home: HalfInningOutcome,
+    pub home: Option<HalfInningOutcome>,
 }
 
+/// Where an inning falls in the game, which decides the late inning rules that apply
+#[derive(Clone, Copy, Debug)]
+pub struct InningSituation {
+    /// Zero based
+    pub inning_index: usize,
+    pub away_score: Score,
+    pub home_score: Score,
+}
+
+impl InningSituation {
+    pub fn is_extra_inning(&self, rules: &Rules) -> bool {
+        self.inning_index >= rules.innings as usize
+    }
+
+    /// Runs the home team needs in the bottom half to end the game, once the
+    /// game can end in this inning. `Some(0)` means it does not need to bat,
+    /// either leading in the final inning or by the mercy rule's margin.
+    pub fn runs_to_win(&self, away_runs: Score, rules: &Rules) -> Option<Score> {
+        let away_score = self.away_score + away_runs;
+        let mercy_lead = rules.mercy_rule.is_some_and(|mercy| {
+            self.inning_index + 1 >= mercy.after_innings as usize
+                && self.home_score >= away_score + mercy.runs
+        });
+
+        if mercy_lead {
+            Some(0)
+        } else {
+            (self.inning_index + 1 >= rules.innings as usize)
+                .then(|| (away_score + 1).saturating_sub(self.home_score))
+        }
+    }
+}
+
+/// From the last regulation inning on, the bottom half is skipped if the home
+/// team leads after the top, and ends as soon as the home team takes the lead.
+/// It is also skipped once the home team leads by the mercy rule's margin.
+#[allow(clippy::too_many_arguments)]
 pub fn simulate_inning(
     away_batting_index: u8,
     away_team: &Team,
     home_batting_index: u8,
     home_team: &Team,
+    situation: InningSituation,
+    rules: &Rules,
+    ballpark: &Ballpark,
+    conditions: &GameConditions,
     decider: &mut impl Decider,
 ) -> InningRecord {
-    let away = simulate_half_inning(away_batting_index, away_team, home_team, decider);
-    let home = simulate_half_inning(home_batting_index, home_team, away_team, decider);
+    let is_extra_inning = situation.is_extra_inning(rules);
+
+    let away = simulate_half_inning(
+        away_batting_index,
+        away_team,
+        home_team,
+        starting_bases(away_batting_index, is_extra_inning, rules),
+        None,
+        situation.inning_index,
+        rules,
+        ballpark,
+        conditions,
+        decider,
+    );
+
+    let home = match situation.runs_to_win(away.outcome.runs_scored, rules) {
+        Some(0) => None,
+        runs_to_win => Some(simulate_half_inning(
+            home_batting_index,
+            home_team,
+            away_team,
+            starting_bases(home_batting_index, is_extra_inning, rules),
+            runs_to_win,
+            situation.inning_index,
+            rules,
+            ballpark,
+            conditions,
+            decider,
+        )),
+    };
+
     let outcome = InningOutcome {
         away: away.outcome.clone(),
-        home: home.outcome.clone(),
+        home: home.as_ref().map(|home| home.outcome.clone()),
     };
 
     InningRecord {
@@ -41,3 +112,14 @@ pub fn simulate_inning(
         outcome,
     }
 }
+
+/// Runners placed before the first pitch of a half inning
+pub fn starting_bases(batting_index: u8, is_extra_inning: bool, rules: &Rules) -> [Option<u8>; 3] {
+    if is_extra_inning && rules.extra_innings_runner {
+        // The batter due up before the leadoff hitter made the last out last inning
+        let runner = (batting_index + rules.players_per_lineup - 1) % rules.players_per_lineup;
+        [None, Some(runner), None]
+    } else {
+        [None, None, None]
+    }
+}
@@ -23,11 +23,36 @@ lazy_static! {
     pub static ref FOULS_PER_STRIKE_CONTACT: f64 =
         TOTAL_STRIKE_SWING_CONTACT_FOULS as f64 / TOTAL_STRIKE_SWING_CONTACTS as f64;
 
-    pub static ref MIN_HOME_RUN_LAUNCH_PERCENTAGE: f64 = 1u64 as f64 / 5 as f64;
-    pub static ref MAX_HOME_RUN_LAUNCH_PERCENTAGE: f64 = 5u64 as f64 / 9 as f64;
-    pub static ref MIN_HOME_RUN_VELOCITY: f64 = 11.0f64 / 8 as f64;
+    /// The rates above split by count, indexed `[balls][strikes]`
+    pub static ref COUNT_RATES: [[CountRates; 3]; 4] = std::array::from_fn(|balls| {
+        std::array::from_fn(|strikes| CountRates {
+            balls_per_pitch: *BALLS_PER_PITCH * BALL_COUNT_SPLITS[balls][strikes],
+            swings_per_ball: *SWINGS_PER_BALL * BALL_SWING_COUNT_SPLITS[balls][strikes],
+            contacts_per_ball_swing:
+                *CONTACTS_PER_BALL_SWING * CONTACT_COUNT_SPLITS[balls][strikes],
+            fouls_per_ball_contact: *FOULS_PER_BALL_CONTACT * FOUL_COUNT_SPLITS[strikes],
+            swings_per_strike: *SWINGS_PER_STRIKE * STRIKE_SWING_COUNT_SPLITS[balls][strikes],
+            contacts_per_strike_swing:
+                *CONTACTS_PER_STRIKE_SWING * CONTACT_COUNT_SPLITS[balls][strikes],
+            fouls_per_strike_contact: *FOULS_PER_STRIKE_CONTACT * FOUL_COUNT_SPLITS[strikes],
+        })
+    });
 
-    static ref HIT_AVERAGE_SPEED: f64 = 125.0;
+    /// Only inside pitches can hit the batter
+    pub static ref HIT_BY_PITCHES_PER_INSIDE_BALL: f64 =
+        TOTAL_HIT_BY_PITCHES as f64 / (TOTAL_BALLS as f64 / 3.0);
+
+    /// Only counted with runners on, as there is no one to advance otherwise
+    pub static ref WILD_PITCHES_PER_BALL: f64 =
+        TOTAL_WILD_PITCHES as f64 / TOTAL_BALLS_WITH_RUNNERS_ON as f64;
+
+    pub static ref PASSED_BALLS_PER_BALL: f64 =
+        TOTAL_PASSED_BALLS as f64 / TOTAL_BALLS_WITH_RUNNERS_ON as f64;
+
+    pub static ref CATCHER_INTERFERENCES_PER_SWING: f64 =
+        TOTAL_CATCHER_INTERFERENCES as f64 / (TOTAL_BALL_SWINGS + TOTAL_STRIKE_SWINGS) as f64;
+
+    static ref HIT_AVERAGE_SPEED: f64 = 135.0;
     pub static ref HIT_EXIT_SPEED: Stat = Stat {
         average: *HIT_AVERAGE_SPEED,
         std_dev: 35.0,
@@ -40,6 +65,41 @@ lazy_static! {
         range: (-85.0, 85.0),
     };
 
+    /// Spin either side of what the launch angle and direction put on, in rpm
+    pub static ref HIT_SPIN_SPREAD: Stat = Stat {
+        average: 0.0,
+        std_dev: 600.0,
+        range: (-2000.0, 2000.0),
+    };
+
+    /// Bunts are pushed down into the ground, in degrees
+    pub static ref BUNT_LAUNCH_ANGLE: Stat = Stat {
+        average: -20.0,
+        std_dev: 15.0,
+        range: (-60.0, 60.0),
+    };
+
+    /// In feet per second
+    pub static ref BUNT_EXIT_SPEED: Stat = Stat {
+        average: 40.0,
+        std_dev: 10.0,
+        range: (10.0, 80.0),
+    };
+
+    /// Fouls are mishit, popped up or topped more often than balls in play
+    pub static ref FOUL_LAUNCH_ANGLE: Stat = Stat {
+        average: 20.0,
+        std_dev: 40.0,
+        range: (-85.0, 85.0),
+    };
+
+    /// In feet per second
+    pub static ref FOUL_EXIT_SPEED: Stat = Stat {
+        average: 120.0,
+        std_dev: 30.0,
+        range: (10.0, 160.0),
+    };
+
     /// In feet per second
     pub static ref BASERUNNER_SPEED: Stat = Stat {
         average: 25.0,
@@ -91,6 +151,54 @@ lazy_static! {
     };
 }
 
+/// Pitch decision rates for a single count
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct CountRates {
+    pub balls_per_pitch: f64,
+    pub swings_per_ball: f64,
+    pub contacts_per_ball_swing: f64,
+    pub fouls_per_ball_contact: f64,
+    pub swings_per_strike: f64,
+    pub contacts_per_strike_swing: f64,
+    pub fouls_per_strike_contact: f64,
+}
+
+/// Rates for the count. Counts past three balls or two strikes, possible
+/// under non standard `Rules`, use the full count's rates.
+pub fn count_rates(balls: u8, strikes: u8) -> CountRates {
+    COUNT_RATES[balls.min(3) as usize][strikes.min(2) as usize]
+}
+
+// Count splits, as multipliers of the overall rates, indexed `[balls][strikes]`.
+// Pitchers fill the zone when behind, and batters take on 3-0 but protect with two
+// strikes, chasing and missing more. Tuned to major league strikeout and walk rates.
+const BALL_COUNT_SPLITS: [[f64; 3]; 4] = [
+    [1.02, 1.12, 1.27],
+    [0.98, 1.06, 1.20],
+    [0.90, 0.96, 1.08],
+    [0.76, 0.86, 0.92],
+];
+const BALL_SWING_COUNT_SPLITS: [[f64; 3]; 4] = [
+    [0.67, 1.08, 1.28],
+    [0.88, 1.14, 1.38],
+    [0.81, 1.18, 1.45],
+    [0.17, 1.01, 1.58],
+];
+const STRIKE_SWING_COUNT_SPLITS: [[f64; 3]; 4] = [
+    [0.75, 1.07, 1.28],
+    [0.96, 1.13, 1.34],
+    [1.00, 1.18, 1.37],
+    [0.21, 1.21, 1.44],
+];
+const CONTACT_COUNT_SPLITS: [[f64; 3]; 4] = [
+    [1.00, 1.00, 0.95],
+    [1.00, 1.00, 0.95],
+    [0.97, 1.00, 0.95],
+    [0.97, 0.97, 0.95],
+];
+/// Indexed by strikes only, as two strike swings fight pitches off
+const FOUL_COUNT_SPLITS: [f64; 3] = [0.92, 1.00, 1.12];
+
 const TOTAL_PITCHES: u32 = 10_894_935;
 const TOTAL_BALLS: u32 = 5_359_317;
 const TOTAL_BALL_SWINGS: u32 = 1_593_720;
@@ -100,7 +208,65 @@ const TOTAL_STRIKES: u32 = TOTAL_PITCHES - TOTAL_BALLS;
 const TOTAL_STRIKE_SWINGS: u32 = 3_468_221;
 const TOTAL_STRIKE_SWING_CONTACTS: u32 = 2_905_144;
 const TOTAL_STRIKE_SWING_CONTACT_FOULS: u32 = 1_363_718;
+const TOTAL_HIT_BY_PITCHES: u32 = 31_642;
+const TOTAL_WILD_PITCHES: u32 = 12_894;
+const TOTAL_PASSED_BALLS: u32 = 2_187;
+const TOTAL_BALLS_WITH_RUNNERS_ON: u32 = 2_304_506;
+const TOTAL_CATCHER_INTERFERENCES: u32 = 581;
+
+/// Pitching around a batter shades the pitch toward a ball
+pub const PITCH_AROUND_BALL_BIAS: i8 = 64;
+/// Contact and exit speed gained by a batter facing a pitcher from the opposite side
+pub const PLATOON_CONTACT_BIAS: i8 = 12;
+pub const PLATOON_HIT_SPEED_BIAS: i8 = 10;
+/// Inches a pitch misses its target by, as one standard deviation
+pub const PITCH_COMMAND_SPREAD: f64 = 6.0;
+/// Inches outside the zone a pitcher aims when throwing a ball
+pub const BALL_TARGET_OFFSET: f64 = 3.0;
+/// Inches either side of the edge of the zone where called strikes are in doubt
+pub const CALLED_STRIKE_EDGE_BAND: f64 = 2.0;
+
+/// Seconds of takeoff delay saved by runners going with the pitch
+pub const RUNNING_WITH_PITCH_HEAD_START: f64 = 1.5;
+/// Launch angles below this, in degrees, are caught too quickly for runners
+/// going with the pitch to read
+pub const LINE_DRIVE_MAX_ANGLE: f64 = 25.0;
+/// Feet off the base a runner going with the pitch is when a line drive is caught
+pub const LINE_DRIVE_RUNNER_LEAD: f64 = 50.0;
+
+/// Share of third strikes the batter can run on that get away from the catcher
+pub const DROPPED_THIRD_STRIKES_PER_STRIKEOUT: f64 = 0.03;
+/// Furthest a dropped third strike gets away from the catcher, in feet
+pub const MAX_DROPPED_THIRD_STRIKE_CAROM: f64 = 30.0;
+
+/// Sacrifices are squared around for early, so are put down more often and
+/// kept fair more often than bunts for a hit
+pub const SACRIFICE_CONTACTS_PER_BUNT: f64 = 0.85;
+pub const SACRIFICE_FOULS_PER_BUNT_CONTACT: f64 = 0.30;
+pub const BUNT_FOR_HIT_CONTACTS_PER_BUNT: f64 = 0.75;
+pub const BUNT_FOR_HIT_FOULS_PER_BUNT_CONTACT: f64 = 0.45;
+/// Launch angles above this, in degrees, pop the bunt up rather than onto the ground
+pub const BUNT_POP_UP_ANGLE: f64 = 15.0;
+/// How quickly a bunt slows rolling on the grass, in feet per second squared
+pub const BUNT_ROLL_DECELERATION: f64 = 15.0;
+
+/// How far a ball rolling to the wall comes back off it, in feet
+pub const WALL_CAROM: f64 = 50.0;
+
+/// Chance a fielder misplays a routine chance that should be an out
+pub const FIELDING_ERRORS_PER_CHANCE: f64 = 0.012;
+/// Feet of range that make a chance twice as likely to be misplayed
+pub const FIELDING_ERROR_RANGE: f64 = 30.0;
+/// Chance a routine throw gets away
+pub const THROWING_ERRORS_PER_THROW: f64 = 0.004;
+/// Feet of throw that make it twice as likely to get away
+pub const THROWING_ERROR_DISTANCE: f64 = 100.0;
 
-pub const LAUNCH_STD_DEV: f64 = 0.35;
-pub const LAUNCH_OFFSET: u64 = 10;
-pub const EXIT_VELOCITY_STD_DEV: f64 = 0.35;
+/// Of a batted ball through the air
+pub const DRAG_COEFFICIENT: f64 = 0.38;
+/// Backspin in rpm of a ball hit flat, climbing with the launch angle. Balls
+/// hit into the ground come off with topspin.
+pub const BACKSPIN_AT_ZERO_LAUNCH: f64 = 500.0;
+pub const BACKSPIN_PER_LAUNCH_DEGREE: f64 = 60.0;
+/// Sidespin in rpm of balls hit down the lines, curving them toward the line
+pub const SIDESPIN_TOWARD_LINE: f64 = 500.0;
@@ -1,180 +1,2172 @@
-#![feature(variant_count)]
-
 mod at_bat;
+mod ballpark;
 mod base_running;
+mod conditions;
 mod consts;
+mod draw;
 mod fielding;
+mod flight;
 mod game;
 mod half_inning;
 mod hit;
 mod inning;
 pub mod levels;
 pub mod location;
+#[cfg(not(any(feature = "wasm", feature = "rand")))]
+mod native_decider;
 mod pitch;
 mod player;
+mod recording_decider;
+mod rules;
+mod scripted_decider;
+#[cfg(feature = "rand")]
+mod seeded_decider;
+mod simulation;
 mod stat;
+mod strategy;
+#[cfg(feature = "wasm")]
+mod wasm;
 
 pub mod prelude {
     pub use crate::{
-        at_bat::{AtBatOutcome, AtBatOutcomeType, AtBatProgress, AtBatRecord},
+        at_bat::{
+            AtBatOutcome, AtBatOutcomeType, AtBatProgress, AtBatRecord, DroppedThirdStrikeRecord,
+        },
+        ballpark::{Ballpark, Fence, FoulTerritory},
         base_running::{BaseMovement, BaseRunningOutcome, BaseRunningRecord, MoveType},
+        conditions::{GameConditions, Roof},
         consts::Consts,
-        fielding::{BallLanding, Fielder, FieldingPlay, FieldingRecord},
-        game::{GameOutcome, GameProgress, GameRecord},
+        draw::Draw,
+        fielding::{BallLanding, ErrorType, Fielder, FieldingError, FieldingPlay, FieldingRecord},
+        game::{GameEndReason, GameOutcome, GameProgress, GameRecord},
         half_inning::{HalfInningOutcome, HalfInningProgress, HalfInningRecord},
-        hit::{HitOutcome, HitRecord, HitType, LaunchAngle, Speed},
+        hit::{HitOutcome, HitRecord, HitType, LaunchAngle, Speed, Spin},
         inning::{InningOutcome, InningRecord},
         levels,
         location::{self, *},
-        pitch::{PitchHeight, PitchLocation, PitchOutcome, PitchRecord, PitchWidth},
-        player::{Player, Team},
+        pitch::{
+            PitchHeight, PitchLocation, PitchOutcome, PitchRecord, PitchType, PitchWidth,
+            PlateLocation, RepertoirePitch, StrikeZone,
+        },
+        player::{Bats, Player, Team, Throws},
+        recording_decider::{
+            DeciderCall, DeciderResult, DeciderTape, RecordedGame, RecordingDecider,
+            ReplayDecider, ReplayError, TapeEntry,
+        },
+        rules::{MercyRule, Rules, RulesError},
+        scripted_decider::ScriptedDecider,
+        simulation::{GameSimulation, PitchEvent},
         stat::{Skill, Stat},
+        strategy::{
+            default_batting_call, default_defensive_alignment, default_fielding_call, BattingCall,
+            DefensiveAlignment, FieldingCall, Situation,
+        },
         Decider, Score,
     };
+
+    #[cfg(feature = "rand")]
+    pub use crate::seeded_decider::SeededDecider;
+    #[cfg(feature = "wasm")]
+    pub use crate::wasm::ExternalDecider;
 }
 
 use std::ops::Range;
 
-pub use {game::simulate_game, game::simulate_game_with_teams, stat::*};
+pub use {
+    at_bat::simulate_at_bat,
+    game::simulate_game,
+    game::simulate_game_with_teams,
+    half_inning::simulate_half_inning,
+    inning::{simulate_inning, InningSituation},
+    stat::*,
+};
+#[cfg(feature = "rand")]
+pub use seeded_decider::SeededDecider;
+#[cfg(feature = "wasm")]
+pub use wasm::ExternalDecider;
+
+use crate::prelude::*;
 
-use serde::{Deserialize, Serialize};
+/// The Decider to use when none is given: JS randomness with the `wasm`
+/// feature, an entropy seeded `SeededDecider` with only the `rand` feature,
+/// and the dependency-free `NativeDecider` with neither
 #[cfg(feature = "wasm")]
-use wasm_bindgen::prelude::*;
+pub type DefaultDecider = ExternalDecider;
+#[cfg(all(not(feature = "wasm"), feature = "rand"))]
+pub type DefaultDecider = SeededDecider;
+#[cfg(not(any(feature = "wasm", feature = "rand")))]
+pub type DefaultDecider = native_decider::NativeDecider;
 
-pub struct ExternalDecider {}
+pub type Score = u16;
+pub trait Decider {
+    fn roll(&mut self, check: u64, count: u64, adjust: u64) -> bool;
+    fn roll_pitch_location(&mut self, height_bias: i8, width_bias: i8) -> pitch::PitchLocation;
+    /// Where a pitch aimed at `target` crosses the plate, missing by `spread`
+    /// inches as one standard deviation. Deciders that only deal in the 3x3
+    /// grid return `None`, and the pitch falls back to `roll_pitch_location`.
+    fn roll_plate_location(
+        &mut self,
+        _target: pitch::PlateLocation,
+        _spread: f64,
+    ) -> Option<pitch::PlateLocation> {
+        None
+    }
+    fn roll_index(&mut self, range: Range<usize>) -> usize;
 
-impl ExternalDecider {
-    pub fn new() -> Self {
-        Self {}
+    /// How the batter approaches the next pitch. Deciders that don't manage
+    /// leave it to `strategy::default_batting_call`.
+    fn batting_call(&mut self, situation: &strategy::Situation) -> strategy::BattingCall {
+        strategy::default_batting_call(situation)
     }
-}
 
-#[wasm_bindgen(module = "$lib/replaceball_deps")]
-extern "C" {
-    pub fn gen_range(start: u64, end: u64) -> u64;
-    pub fn gen_usize_range(start: usize, end: usize) -> usize;
-    pub fn gen_float_range(start: f64, end: f64) -> f64;
-    pub fn gen_normal(avg: f64, std_dist: f64) -> f64;
+    /// How the fielding team handles the batter before the next pitch.
+    /// Deciders that don't manage leave it to `strategy::default_fielding_call`.
+    fn fielding_call(&mut self, situation: &strategy::Situation) -> strategy::FieldingCall {
+        strategy::default_fielding_call(situation)
+    }
+
+    /// Where the fielding team plays for the at bat, set before its first
+    /// pitch. Deciders that don't manage leave it to
+    /// `strategy::default_defensive_alignment`.
+    fn defensive_alignment(
+        &mut self,
+        situation: &strategy::Situation,
+    ) -> strategy::DefensiveAlignment {
+        strategy::default_defensive_alignment(situation)
+    }
+
+    fn flip(&mut self, draw: Draw, probability: f64, bias: i8) -> bool;
+
+    fn roll_uniform(&mut self, draw: Draw, range: Range<f64>) -> f64;
+
+    fn roll_stat(&mut self, draw: Draw, stat: Stat, skill: Skill) -> f64;
+    fn roll_std_dev_skill_stat(&mut self, draw: Draw, stat: Stat, bias: i8) -> f64 {
+        self.roll_std_dev_mult_skill_stat(draw, stat, bias, 1.0)
+    }
+    fn roll_std_dev_mult_skill_stat(
+        &mut self,
+        draw: Draw,
+        stat: Stat,
+        bias: i8,
+        std_dev_mult: f64,
+    ) -> f64 {
+        self.roll_stat(draw, stat, Skill::std_dev_bias_skill(bias, stat, std_dev_mult))
+    }
 }
 
-use crate::prelude::*;
-impl Decider for ExternalDecider {
+/// Lets a borrowed Decider drive a simulation that takes its Decider by value
+impl<D: Decider + ?Sized> Decider for &mut D {
     fn roll(&mut self, check: u64, count: u64, adjust: u64) -> bool {
-        let probability = check + adjust;
-        let roll = gen_range(0, count);
+        (**self).roll(check, count, adjust)
+    }
 
-        roll < probability
+    fn roll_pitch_location(&mut self, height_bias: i8, width_bias: i8) -> pitch::PitchLocation {
+        (**self).roll_pitch_location(height_bias, width_bias)
     }
 
-    fn roll_pitch_location(
+    fn roll_plate_location(
         &mut self,
-        pitch_height_bias: i8,
-        pitch_width_bias: i8,
-    ) -> PitchLocation {
-        let zone_count = 3;
-        let zone_size = std::i8::MAX as i16;
-        let full_range = zone_count * zone_size;
-
-        let width = match (gen_range(0, full_range as u64)
-            .saturating_add_signed(pitch_width_bias as i64) as f32
-            / zone_size as f32) as u8
-        {
-            0 => PitchWidth::Left,
-            1 => PitchWidth::Center,
-            2 | 3 => PitchWidth::Right,
-            i => unreachable!("Pitch location width: {}", i),
+        target: pitch::PlateLocation,
+        spread: f64,
+    ) -> Option<pitch::PlateLocation> {
+        (**self).roll_plate_location(target, spread)
+    }
+
+    fn roll_index(&mut self, range: Range<usize>) -> usize {
+        (**self).roll_index(range)
+    }
+
+    fn batting_call(&mut self, situation: &strategy::Situation) -> strategy::BattingCall {
+        (**self).batting_call(situation)
+    }
+
+    fn fielding_call(&mut self, situation: &strategy::Situation) -> strategy::FieldingCall {
+        (**self).fielding_call(situation)
+    }
+
+    fn defensive_alignment(
+        &mut self,
+        situation: &strategy::Situation,
+    ) -> strategy::DefensiveAlignment {
+        (**self).defensive_alignment(situation)
+    }
+
+    fn flip(&mut self, draw: Draw, probability: f64, bias: i8) -> bool {
+        (**self).flip(draw, probability, bias)
+    }
+
+    fn roll_uniform(&mut self, draw: Draw, range: Range<f64>) -> f64 {
+        (**self).roll_uniform(draw, range)
+    }
+
+    fn roll_stat(&mut self, draw: Draw, stat: Stat, skill: Skill) -> f64 {
+        (**self).roll_stat(draw, stat, skill)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        at_bat::{simulate_at_bat, AtBatSimulation}, base_running::simulate_base_running, fielding::FieldingEvent,
+        flight::Trajectory,
+        half_inning::simulate_half_inning, inning::{simulate_inning, InningSituation},
+        prelude::*,
+    };
+
+    fn walk(decider: ScriptedDecider) -> ScriptedDecider {
+        // Four balls, taken each time
+        (0..4).fold(decider, |decider, _| decider.next_flip(Draw::PitchIsBall, true))
+    }
+
+    fn in_play(
+        decider: ScriptedDecider,
+        direction: f64,
+        launch_angle: f64,
+        exit_speed: f64,
+    ) -> ScriptedDecider {
+        // Strike, swung on, contact, kept fair
+        decider
+            .next_flip(Draw::SwingOnStrike, true)
+            .next_flip(Draw::ContactOnStrikeSwing, true)
+            .next_value(Draw::HitDirection, direction)
+            .next_value(Draw::HitLaunchAngle, launch_angle)
+            .next_value(Draw::HitExitSpeed, exit_speed)
+    }
+
+    /// What an at bat is played under, for the few tests that need more than
+    /// default teams under regulation rules in the default park
+    #[derive(Default)]
+    struct AtBatSetup {
+        batting_team: Team,
+        fielding_team: Team,
+        rules: Rules,
+        ballpark: Ballpark,
+    }
+
+    fn at_bat(decider: &mut ScriptedDecider, base_state: &[Option<u8>; 3]) -> AtBatRecord {
+        at_bat_with(&AtBatSetup::default(), decider, base_state)
+    }
+
+    /// Leads off the first inning with no outs
+    fn at_bat_with(
+        setup: &AtBatSetup,
+        decider: &mut impl Decider,
+        base_state: &[Option<u8>; 3],
+    ) -> AtBatRecord {
+        simulate_at_bat(
+            0,
+            &setup.batting_team,
+            &setup.fielding_team,
+            &setup.rules,
+            &setup.ballpark,
+            &GameConditions::default(),
+            decider,
+            base_state,
+            0,
+            0,
+        )
+    }
+
+    fn fielding_record(record: &AtBatRecord) -> &FieldingRecord {
+        match &record.outcome.outcome_type {
+            AtBatOutcomeType::Hit(HitRecord {
+                outcome: HitOutcome::InPlay(fielding_record),
+                ..
+            }) => fielding_record,
+            outcome => panic!("Expected a ball in play, found {:?}", outcome),
+        }
+    }
+
+    #[test]
+    fn unscripted_at_bat_is_called_strikeout() {
+        let record = at_bat(&mut ScriptedDecider::new(), &[None, None, None]);
+
+        assert_eq!(record.outcome.outcome_type, AtBatOutcomeType::Out);
+        assert_eq!(record.pitches.len(), 3);
+        assert!(record
+            .pitches
+            .iter()
+            .all(|(pitch, _)| pitch.outcome == PitchOutcome::Strike(false)));
+    }
+
+    #[test]
+    fn four_balls_is_a_walk() {
+        let mut decider = walk(ScriptedDecider::new());
+        let record = at_bat(&mut decider, &[None, None, None]);
+
+        assert_eq!(record.outcome.outcome_type, AtBatOutcomeType::Walk);
+        assert_eq!(record.pitches.len(), 4);
+        assert!(decider.is_exhausted());
+    }
+
+    #[test]
+    fn bases_loaded_walk_forces_in_a_run() {
+        let mut decider = ScriptedDecider::new();
+        for _ in 0..4 {
+            decider = walk(decider);
+        }
+
+        let record = simulate_half_inning(
+            0,
+            &Team::default(),
+            &Team::default(),
+            [None, None, None],
+            None,
+            0,
+            &Rules::default(),
+            &Ballpark::default(),
+            &GameConditions::default(),
+            &mut decider,
+        );
+
+        // Four walks followed by three unscripted strikeouts
+        assert_eq!(record.at_bats.len(), 7);
+        assert_eq!(record.outcome.runs_scored, 1);
+        assert_eq!(record.at_bats[3].1.bases, [true, true, true]);
+        assert_eq!(record.at_bats[3].1.score_change, 1);
+    }
+
+    #[test]
+    fn ground_ball_to_short_forces_runner_at_second() {
+        let mut decider = in_play(ScriptedDecider::new(), 25.0, 0.0, 60.0);
+        let record = at_bat(&mut decider, &[Some(5), None, None]);
+        let fielding_record = fielding_record(&record);
+
+        match &fielding_record.landing {
+            BallLanding::Landed(_, play) => {
+                assert_eq!(play.from, Fielder::Shortstop);
+                assert_eq!(play.base, Consts::SECOND);
+            }
+            landing => panic!("Expected ground ball to be fielded, found {:?}", landing),
+        }
+
+        let outcome = &fielding_record.base_running_record.outcome;
+        assert_eq!(outcome.batter_hit_type, HitType::FieldersChoice);
+        assert_eq!(outcome.outs_made, 1);
+        assert_eq!(outcome.ending_base_state, [Some(0), None, None]);
+    }
+
+    #[test]
+    fn bases_loaded_single_to_right_scores_two() {
+        let mut decider = in_play(ScriptedDecider::new(), 65.0, 0.0, 90.0);
+        let record = at_bat(&mut decider, &[Some(6), Some(7), Some(8)]);
+        let outcome = &fielding_record(&record).base_running_record.outcome;
+
+        assert_eq!(outcome.batter_hit_type, HitType::Single);
+        assert_eq!(outcome.runs_scored, 2);
+        assert_eq!(outcome.outs_made, 0);
+        assert_eq!(outcome.ending_base_state, [Some(0), Some(6), None]);
+    }
+
+    #[test]
+    fn deep_fly_to_center_is_a_sac_fly() {
+        let mut decider = in_play(ScriptedDecider::new(), 45.0, 30.0, 110.0);
+        let record = at_bat(&mut decider, &[None, None, Some(5)]);
+        let fielding_record = fielding_record(&record);
+
+        assert!(matches!(
+            fielding_record.landing,
+            BallLanding::Out(Fielder::CenterFielder, _)
+        ));
+
+        let outcome = &fielding_record.base_running_record.outcome;
+        assert_eq!(outcome.batter_hit_type, HitType::Out);
+        assert_eq!(outcome.outs_made, 1);
+        assert_eq!(outcome.runs_scored, 1);
+        assert_eq!(outcome.ending_base_state, [None, None, None]);
+    }
+
+    #[test]
+    fn shallow_fly_holds_runner_at_third() {
+        let mut decider = in_play(ScriptedDecider::new(), 45.0, 20.0, 110.0);
+        let record = at_bat(&mut decider, &[None, None, Some(5)]);
+        let outcome = &fielding_record(&record).base_running_record.outcome;
+
+        assert_eq!(outcome.outs_made, 1);
+        assert_eq!(outcome.runs_scored, 0);
+        assert_eq!(outcome.ending_base_state, [None, None, Some(5)]);
+    }
+
+    #[test]
+    fn slow_runner_thrown_out_tagging_from_third() {
+        let mut decider = ScriptedDecider::new()
+            .next_value(Draw::ThrowSpeed, 100.0)
+            .next_value(Draw::BaserunnerSpeed, 10.0);
+        let landing = BallLanding::Out(
+            Fielder::RightFielder,
+            Location {
+                direction: HitDirection(65.0),
+                distance: Distance(260.0),
+            },
+        );
+
+        let record = simulate_base_running(
+            0,
+            &Team::default(),
+            &Team::default(),
+            &landing,
+            &[None, None, Some(5)],
+            false,
+            &mut decider,
+        );
+
+        assert_eq!(record.outcome.outs_made, 2);
+        assert_eq!(record.outcome.runs_scored, 0);
+        assert_eq!(
+            record.movements[0],
+            BaseMovement {
+                starting_base: Some(Consts::THIRD),
+                bases_moved: MoveType::Out(Consts::HOME),
+            }
+        );
+    }
+
+    #[test]
+    fn deep_fly_to_center_is_a_home_run() {
+        let mut decider = in_play(ScriptedDecider::new(), 45.0, 30.0, 160.0);
+        let record = at_bat(&mut decider, &[None, None, None]);
+
+        match record.outcome.outcome_type {
+            AtBatOutcomeType::Hit(hit_record) => assert_eq!(hit_record.outcome, HitOutcome::HomeRun),
+            outcome => panic!("Expected a home run, found {:?}", outcome),
+        }
+    }
+
+    #[test]
+    fn grand_slam_clears_the_bases() {
+        let mut decider = ScriptedDecider::new();
+        for _ in 0..3 {
+            decider = walk(decider);
+        }
+        decider = in_play(decider, 45.0, 30.0, 160.0);
+
+        let record = simulate_half_inning(
+            0,
+            &Team::default(),
+            &Team::default(),
+            [None, None, None],
+            None,
+            0,
+            &Rules::default(),
+            &Ballpark::default(),
+            &GameConditions::default(),
+            &mut decider,
+        );
+
+        let (_, progress) = &record.at_bats[3];
+        assert_eq!(progress.score_change, 4);
+        assert_eq!(progress.bases, [false, false, false]);
+        assert_eq!(record.outcome.runs_scored, 4);
+        assert_eq!(record.outcome.total_hits, 1);
+    }
+
+    #[test]
+    fn three_ball_walk_under_youth_rules() {
+        let rules = Rules {
+            balls_per_walk: 3,
+            ..Default::default()
+        };
+        let mut decider = walk(ScriptedDecider::new());
+        let record = at_bat_with(
+            &AtBatSetup {
+                rules,
+                ..Default::default()
+            },
+            &mut decider,
+            &[None, None, None],
+        );
+
+        assert_eq!(record.outcome.outcome_type, AtBatOutcomeType::Walk);
+        assert_eq!(record.pitches.len(), 3);
+        assert_eq!(record.pitches[2].1.balls, 3);
+    }
+
+    #[test]
+    fn seven_inning_game_ends_after_seventh() {
+        let rules = Rules {
+            innings: 7,
+            ..Default::default()
+        };
+        // The away team walks in a run to open the game, then every batter strikes out
+        let mut decider = ScriptedDecider::new();
+        for _ in 0..4 {
+            decider = walk(decider);
+        }
+
+        let game = crate::simulate_game_with_teams(
+            &mut decider,
+            &Team::default(),
+            &Team::default(),
+            &rules,
+            &Ballpark::default(),
+            &GameConditions::default(),
+        )
+        .unwrap();
+
+        assert_eq!(game.innings.len(), 7);
+        assert_eq!(game.outcome.away_score, 1);
+        assert_eq!(game.outcome.home_score, 0);
+        assert_eq!(game.outcome.end_reason, GameEndReason::Regulation);
+        assert_eq!(game.rules, rules);
+    }
+
+    #[test]
+    fn home_team_leading_skips_bottom_half() {
+        let record = simulate_inning(
+            0,
+            &Team::default(),
+            0,
+            &Team::default(),
+            InningSituation {
+                inning_index: 8,
+                away_score: 2,
+                home_score: 3,
+            },
+            &Rules::default(),
+            &Ballpark::default(),
+            &GameConditions::default(),
+            &mut ScriptedDecider::new(),
+        );
+
+        assert!(record.home.is_none());
+        assert!(record.outcome.home.is_none());
+    }
+
+    #[test]
+    fn walk_off_ends_bottom_half() {
+        // Away strikes out in order on nine pitches, then home walks in the winning run
+        let mut decider = (0..9).fold(ScriptedDecider::new(), |decider, _| {
+            decider.next_flip(Draw::PitchIsBall, false)
+        });
+        for _ in 0..4 {
+            decider = walk(decider);
+        }
+
+        let record = simulate_inning(
+            0,
+            &Team::default(),
+            0,
+            &Team::default(),
+            InningSituation {
+                inning_index: 8,
+                away_score: 0,
+                home_score: 0,
+            },
+            &Rules::default(),
+            &Ballpark::default(),
+            &GameConditions::default(),
+            &mut decider,
+        );
+
+        let home = record.home.expect("Home team should bat in a tied inning");
+        assert_eq!(home.at_bats.len(), 4);
+        assert_eq!(home.outcome.runs_scored, 1);
+        assert_eq!(home.at_bats[3].1.outs, 0);
+    }
+
+    #[test]
+    #[cfg(feature = "rand")]
+    fn home_team_never_bats_with_the_game_won() {
+        for seed in 0..20 {
+            let game = crate::simulate_game(&mut SeededDecider::new(seed));
+            let rules = Rules::default();
+
+            for (index, (inning, progress)) in game.innings.iter().enumerate() {
+                let is_last = index + 1 == game.innings.len();
+                if index + 1 < rules.innings as usize {
+                    assert!(inning.home.is_some());
+                } else if !is_last {
+                    assert_eq!(progress.home_score, progress.away_score);
+                }
+            }
+
+            let (last, progress) = game.innings.last().unwrap();
+            if last.home.is_none() {
+                assert!(progress.home_score > progress.away_score);
+            }
+            if game.outcome.end_reason == GameEndReason::WalkOff {
+                assert!(last.home.is_some());
+                assert!(progress.home_score > progress.away_score);
+            }
+            assert_ne!(progress.home_score, progress.away_score);
+        }
+    }
+
+    #[test]
+    fn extra_innings_runner_starts_on_second() {
+        let rules = Rules {
+            extra_innings_runner: true,
+            ..Default::default()
+        };
+        let simulate = |inning_index| {
+            simulate_inning(
+                0,
+                &Team::default(),
+                4,
+                &Team::default(),
+                InningSituation {
+                    inning_index,
+                    away_score: 1,
+                    home_score: 1,
+                },
+                &rules,
+                &Ballpark::default(),
+                &GameConditions::default(),
+                &mut ScriptedDecider::new(),
+            )
+        };
+
+        let regulation = simulate(8);
+        assert_eq!(regulation.away.starting_bases, [None, None, None]);
+
+        let extra = simulate(9);
+        let home = extra.home.expect("Home team should bat in a tied inning");
+        assert_eq!(extra.away.starting_bases, [None, Some(8), None]);
+        assert_eq!(home.starting_bases, [None, Some(3), None]);
+        assert_eq!(home.at_bats[0].1.bases, [false, true, false]);
+    }
+
+    #[test]
+    fn empty_lineup_is_rejected() {
+        let rules = Rules {
+            players_per_lineup: 0,
+            ..Default::default()
         };
+        assert_eq!(rules.validate(), Err(RulesError::ZeroPlayersPerLineup));
+        assert_eq!(Rules::default().validate(), Ok(()));
 
-        let height = match (gen_range(0, full_range as u64)
-            .saturating_add_signed(pitch_height_bias as i64) as f32
-            / zone_size as f32) as u8
+        #[cfg(feature = "serde")]
         {
-            0 => PitchHeight::High,
-            1 => PitchHeight::Middle,
-            2 | 3 => PitchHeight::Low,
-            i => unreachable!("Pitch location height: {}", i),
+            let mut json = serde_json::to_value(Rules::default()).unwrap();
+            assert!(serde_json::from_value::<Rules>(json.clone()).is_ok());
+            json["players_per_lineup"] = 0.into();
+            assert!(serde_json::from_value::<Rules>(json).is_err());
+        }
+    }
+
+    #[test]
+    fn half_inning_without_outs_is_rejected() {
+        let rules = Rules {
+            outs_per_half_inning: 0,
+            ..Default::default()
         };
+        assert_eq!(rules.validate(), Err(RulesError::ZeroOutsPerHalfInning));
+    }
 
-        PitchLocation { width, height }
+    #[test]
+    fn lineup_longer_than_batting_order_is_rejected() {
+        let rules = Rules {
+            players_per_lineup: Team::BATTING_ORDER_LENGTH as u8 + 1,
+            ..Default::default()
+        };
+        assert_eq!(rules.validate(), Err(RulesError::LineupLongerThanBattingOrder));
+
+        let full_lineup = Rules {
+            players_per_lineup: Team::BATTING_ORDER_LENGTH as u8,
+            ..Default::default()
+        };
+        assert_eq!(full_lineup.validate(), Ok(()));
     }
 
-    fn roll_index(&mut self, range: std::ops::Range<usize>) -> usize {
-        gen_usize_range(range.start, range.end)
+    #[test]
+    fn half_inning_without_at_bats_is_rejected() {
+        let rules = Rules {
+            max_at_bats_per_half_inning: 0,
+            ..Default::default()
+        };
+        assert_eq!(rules.validate(), Err(RulesError::ZeroMaxAtBatsPerHalfInning));
     }
 
-    fn roll_stat(&mut self, stat: Stat, skill: Skill) -> f64 {
-        let sample = gen_normal(
-            stat.average * skill.average_multiplier + skill.average_shift,
-            stat.std_dev * skill.std_dev_multiplier,
+    #[test]
+    fn unplayable_rules_are_returned_as_an_error() {
+        let rules = Rules {
+            outs_per_half_inning: 0,
+            ..Default::default()
+        };
+        let game = crate::simulate_game_with_teams(
+            &mut ScriptedDecider::new(),
+            &Team::default(),
+            &Team::default(),
+            &rules,
+            &Ballpark::default(),
+            &GameConditions::default(),
         );
 
-        if sample < stat.range.0 {
-            stat.range.0
-        } else if stat.range.1 < sample {
-            stat.range.1
-        } else {
-            sample
+        assert_eq!(game.unwrap_err(), RulesError::ZeroOutsPerHalfInning);
+    }
+
+    #[test]
+    fn mercy_rule_ends_game_early() {
+        let rules = Rules {
+            mercy_rule: Some(MercyRule {
+                runs: 1,
+                after_innings: 1,
+            }),
+            ..Default::default()
+        };
+        // Away walks in a run in the first, then everyone strikes out
+        let mut decider = ScriptedDecider::new();
+        for _ in 0..4 {
+            decider = walk(decider);
         }
+
+        let game = crate::simulate_game_with_teams(
+            &mut decider,
+            &Team::default(),
+            &Team::default(),
+            &rules,
+            &Ballpark::default(),
+            &GameConditions::default(),
+        )
+        .unwrap();
+
+        assert_eq!(game.innings.len(), 1);
+        assert_eq!(game.outcome.away_score, 1);
+        assert_eq!(game.outcome.end_reason, GameEndReason::Mercy);
     }
 
-    fn flip(&mut self, probability: f64, bias: i8) -> bool {
-        (gen_float_range(0.0, 1.0) + (bias as f64 / std::i8::MAX as f64)) < probability
+    #[test]
+    fn mercy_rule_skips_bottom_half_when_home_leads() {
+        let rules = Rules {
+            mercy_rule: Some(MercyRule {
+                runs: 1,
+                after_innings: 2,
+            }),
+            ..Default::default()
+        };
+        // Away strikes out looking in the top of the first, then home walks in a run
+        let mut decider = (0..9).fold(ScriptedDecider::new(), |decider, _| {
+            decider.next_flip(Draw::PitchIsBall, false)
+        });
+        for _ in 0..4 {
+            decider = walk(decider);
+        }
+
+        let game = crate::simulate_game_with_teams(
+            &mut decider,
+            &Team::default(),
+            &Team::default(),
+            &rules,
+            &Ballpark::default(),
+            &GameConditions::default(),
+        )
+        .unwrap();
+
+        assert_eq!(game.innings.len(), 2);
+        assert!(game.innings[1].0.home.is_none());
+        assert_eq!(game.outcome.home_score, 1);
+        assert_eq!(game.outcome.end_reason, GameEndReason::Mercy);
     }
 
-    fn roll_uniform(&mut self, range: std::ops::Range<f64>) -> f64 {
-        gen_float_range(range.start, range.end)
+    #[test]
+    fn scoreless_game_ends_in_tie_at_max_innings() {
+        let rules = Rules {
+            max_innings: Some(12),
+            ..Default::default()
+        };
+        let game = crate::simulate_game_with_teams(
+            &mut ScriptedDecider::new(),
+            &Team::default(),
+            &Team::default(),
+            &rules,
+            &Ballpark::default(),
+            &GameConditions::default(),
+        )
+        .unwrap();
+
+        assert_eq!(game.innings.len(), 12);
+        assert_eq!(game.outcome.end_reason, GameEndReason::Tie);
     }
-}
 
-#[derive(Serialize, Deserialize)]
-struct Test {
-    x: i32,
-    y: f32,
-    name: String,
-}
+    #[test]
+    fn at_bat_cap_ends_game() {
+        let rules = Rules {
+            max_at_bats_per_half_inning: 2,
+            ..Default::default()
+        };
+        let game = crate::simulate_game_with_teams(
+            &mut ScriptedDecider::new(),
+            &Team::default(),
+            &Team::default(),
+            &rules,
+            &Ballpark::default(),
+            &GameConditions::default(),
+        )
+        .unwrap();
 
-#[wasm_bindgen]
-pub fn wasm_simulate_game_with_teams(
-    home_team: JsValue,
-    away_team: JsValue,
-) -> JsValue {
-    let home_team: Team = serde_wasm_bindgen::from_value(home_team).unwrap();
-    let away_team: Team = serde_wasm_bindgen::from_value(away_team).unwrap();
-
-    let game = simulate_game_with_teams(
-        &mut ExternalDecider::new(),
-        &home_team,
-        &away_team,
-    );
-
-    serde_wasm_bindgen::to_value(&game).unwrap()
-}
+        assert_eq!(game.innings.len(), 1);
+        assert!(game.innings[0].0.outcome.away.reached_at_bat_cap);
+        assert_eq!(game.outcome.end_reason, GameEndReason::AtBatCap);
+    }
 
-#[wasm_bindgen]
-pub fn wasm_simulate_game() -> JsValue {
-    let game = simulate_game(&mut ExternalDecider::new());
+    #[test]
+    fn launch_angle_bias_shifts_the_average() {
+        let launch_angle = |batting_team: &Team, fielding_team: &Team| {
+            let mut decider = ScriptedDecider::new()
+                .next_flip(Draw::SwingOnStrike, true)
+                .next_flip(Draw::ContactOnStrikeSwing, true);
+            let record = at_bat_with(
+                &AtBatSetup {
+                    batting_team: batting_team.clone(),
+                    fielding_team: fielding_team.clone(),
+                    ..Default::default()
+                },
+                &mut decider,
+                &[None, None, None],
+            );
 
-    serde_wasm_bindgen::to_value(&game).unwrap()
-}
+            match record.outcome.outcome_type {
+                AtBatOutcomeType::Hit(hit_record) => hit_record.launch_angle.0,
+                outcome => panic!("Expected a hit, found {:?}", outcome),
+            }
+        };
+        let mut fly_ball_hitter = Team::default();
+        fly_ball_hitter.fielders[Fielder::Catcher as usize].hitter_launch_angle_bias = 60;
+        let mut ground_ball_pitcher = Team::default();
+        ground_ball_pitcher.fielders[Fielder::Pitcher as usize].pitcher_launch_angle_bias = 60;
 
-pub type Score = u16;
-pub trait Decider {
-    fn roll(&mut self, check: u64, count: u64, adjust: u64) -> bool;
-    fn roll_pitch_location(&mut self, height_bias: i8, width_bias: i8) -> pitch::PitchLocation;
-    fn roll_index(&mut self, range: Range<usize>) -> usize;
+        let average = launch_angle(&Team::default(), &Team::default());
+        assert!(launch_angle(&fly_ball_hitter, &Team::default()) > average);
+        assert!(launch_angle(&Team::default(), &ground_ball_pitcher) < average);
+    }
 
-    fn flip(&mut self, probability: f64, bias: i8) -> bool;
+    #[test]
+    fn saturated_launch_angle_bias_does_not_overflow() {
+        let mut batting_team = Team::default();
+        batting_team.fielders[Fielder::Catcher as usize].hitter_launch_angle_bias = i8::MIN;
+        let mut fielding_team = Team::default();
+        fielding_team.fielders[Fielder::Pitcher as usize].pitcher_launch_angle_bias = i8::MAX;
+        let mut decider = ScriptedDecider::new()
+            .next_flip(Draw::SwingOnStrike, true)
+            .next_flip(Draw::ContactOnStrikeSwing, true);
 
-    fn roll_uniform(&mut self, range: Range<f64>) -> f64;
+        let record = at_bat_with(
+            &AtBatSetup {
+                batting_team,
+                fielding_team,
+                ..Default::default()
+            },
+            &mut decider,
+            &[None, None, None],
+        );
 
-    fn roll_stat(&mut self, stat: Stat, skill: Skill) -> f64;
-    fn roll_std_dev_skill_stat(&mut self, stat: Stat, bias: i8) -> f64 {
-        self.roll_std_dev_mult_skill_stat(stat, bias, 1.0)
+        assert!(matches!(record.outcome.outcome_type, AtBatOutcomeType::Hit(_)));
     }
-    fn roll_std_dev_mult_skill_stat(&mut self, stat: Stat, bias: i8, std_dev_mult: f64) -> f64 {
-        self.roll_stat(stat, Skill::std_dev_bias_skill(bias, stat, std_dev_mult))
+
+    fn launch_angle_against(repertoire: Vec<RepertoirePitch>) -> (PitchType, f64) {
+        let mut fielding_team = Team::default();
+        fielding_team.fielders[Fielder::Pitcher as usize].repertoire = repertoire;
+        let mut decider = ScriptedDecider::new()
+            .next_flip(Draw::SwingOnStrike, true)
+            .next_flip(Draw::ContactOnStrikeSwing, true);
+
+        let record = at_bat_with(
+            &AtBatSetup {
+                fielding_team,
+                ..Default::default()
+            },
+            &mut decider,
+            &[None, None, None],
+        );
+
+        match &record.outcome.outcome_type {
+            AtBatOutcomeType::Hit(hit_record) => {
+                (record.pitches[0].0.pitch_type, hit_record.launch_angle.0)
+            }
+            outcome => panic!("Expected a hit, found {:?}", outcome),
+        }
     }
-}
 
-#[cfg(test)]
-mod tests {}
+    #[test]
+    fn sinker_is_hit_on_the_ground_more() {
+        let (fastball, fastball_angle) = launch_angle_against(Vec::new());
+        let (sinker, sinker_angle) = launch_angle_against(vec![PitchType::Sinker.typical(1)]);
+
+        assert_eq!(fastball, PitchType::Fastball);
+        assert_eq!(sinker, PitchType::Sinker);
+        assert!(sinker_angle < fastball_angle);
+    }
+
+    #[test]
+    fn slider_is_hit_less_often_than_fastball() {
+        let contact_bias = |repertoire: Vec<RepertoirePitch>| {
+            let mut fielding_team = Team::default();
+            fielding_team.fielders[Fielder::Pitcher as usize].repertoire = repertoire;
+            let mut decider = RecordingDecider::new(
+                ScriptedDecider::new()
+                    .next_flip(Draw::SwingOnStrike, true)
+                    .next_flip(Draw::ContactOnStrikeSwing, true),
+            );
+            at_bat_with(
+                &AtBatSetup {
+                    fielding_team,
+                    ..Default::default()
+                },
+                &mut decider,
+                &[None, None, None],
+            );
+
+            decider
+                .into_tape()
+                .entries
+                .into_iter()
+                .find_map(|entry| match entry.call {
+                    DeciderCall::Flip {
+                        draw: Draw::ContactOnStrikeSwing,
+                        bias,
+                        ..
+                    } => Some(bias),
+                    _ => None,
+                })
+                .expect("Batter should have swung at a strike")
+        };
+
+        // A higher bias makes the flip less likely to come up contact
+        let fastball = contact_bias(Vec::new());
+        let slider = contact_bias(vec![PitchType::Slider.typical(1)]);
+        assert!(slider > fastball);
+    }
+
+    #[test]
+    fn pitch_type_is_picked_by_usage() {
+        let mut fielding_team = Team::default();
+        fielding_team.fielders[Fielder::Pitcher as usize].repertoire =
+            vec![PitchType::Fastball.typical(3), PitchType::Slider.typical(1)];
+        let mut decider = ScriptedDecider::new()
+            .next_value(Draw::PitchType, 2.5)
+            .next_value(Draw::PitchType, 3.5);
+
+        let record = at_bat_with(
+            &AtBatSetup {
+                fielding_team,
+                ..Default::default()
+            },
+            &mut decider,
+            &[None, None, None],
+        );
+
+        let pitch_types: Vec<PitchType> = record
+            .pitches
+            .iter()
+            .map(|(pitch, _)| pitch.pitch_type)
+            .collect();
+        assert_eq!(pitch_types[..2], [PitchType::Fastball, PitchType::Slider]);
+    }
+
+    fn flip_probabilities(decider: ScriptedDecider, draw: Draw) -> Vec<f64> {
+        let mut decider = RecordingDecider::new(decider);
+        at_bat_with(&AtBatSetup::default(), &mut decider, &[None, None, None]);
+
+        decider
+            .into_tape()
+            .entries
+            .into_iter()
+            .filter_map(|entry| match entry.call {
+                DeciderCall::Flip {
+                    draw: flipped,
+                    probability,
+                    ..
+                } if flipped == draw => Some(probability),
+                _ => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn batters_take_on_three_and_oh_and_protect_with_two_strikes() {
+        // Swing chances at 0-0, 1-0, 2-0 and 3-0
+        let ball_swings = flip_probabilities(walk(ScriptedDecider::new()), Draw::SwingOnBall);
+        assert_eq!(ball_swings.len(), 4);
+        assert!(ball_swings[3] < ball_swings[0]);
+
+        // Swing chances at 0-0, 0-1 and 0-2
+        let strike_swings = flip_probabilities(ScriptedDecider::new(), Draw::SwingOnStrike);
+        assert_eq!(strike_swings.len(), 3);
+        assert!(strike_swings[0] < strike_swings[1] && strike_swings[1] < strike_swings[2]);
+    }
+
+    #[test]
+    fn pitchers_throw_more_strikes_when_behind() {
+        let ball_chances = flip_probabilities(walk(ScriptedDecider::new()), Draw::PitchIsBall);
+
+        assert!(ball_chances.windows(2).all(|pair| pair[1] < pair[0]));
+    }
+
+    #[test]
+    #[cfg(feature = "rand")]
+    fn count_rates_keep_strikeouts_and_walks_realistic() {
+        let mut decider = SeededDecider::new(12);
+        let (mut plate_appearances, mut strikeouts, mut walks) = (0, 0, 0);
+        for _ in 0..100 {
+            let game = crate::simulate_game(&mut decider);
+            let at_bats = game
+                .innings
+                .iter()
+                .flat_map(|(inning, _)| std::iter::once(&inning.away).chain(&inning.home))
+                .flat_map(|half_inning| half_inning.at_bats.iter())
+                .map(|(at_bat, _)| at_bat)
+                .filter(|at_bat| at_bat.outcome.outcome_type != AtBatOutcomeType::Unfinished);
+
+            for at_bat in at_bats {
+                plate_appearances += 1;
+                strikeouts += at_bat
+                    .pitches
+                    .last()
+                    .is_some_and(|(_, count)| count.strikes == 3) as u32;
+                walks += (at_bat.outcome.outcome_type == AtBatOutcomeType::Walk) as u32;
+            }
+        }
+
+        // Shares of plate appearances, around the major league rates
+        let strikeout_rate = strikeouts as f64 / plate_appearances as f64;
+        let walk_rate = walks as f64 / plate_appearances as f64;
+        assert!((0.19..0.26).contains(&strikeout_rate), "{}", strikeout_rate);
+        assert!((0.06..0.11).contains(&walk_rate), "{}", walk_rate);
+    }
+
+    #[test]
+    fn hit_by_pitch_forces_runner_to_second() {
+        let mut decider = ScriptedDecider::new()
+            .next_flip(Draw::PitchIsBall, true)
+            .next_pitch_location(PitchHeight::Middle, PitchWidth::Right)
+            .next_flip(Draw::HitByPitch, true);
+
+        let record = simulate_half_inning(
+            0,
+            &Team::default(),
+            &Team::default(),
+            [Some(8), None, None],
+            None,
+            0,
+            &Rules::default(),
+            &Ballpark::default(),
+            &GameConditions::default(),
+            &mut decider,
+        );
+
+        let (hit_by_pitch, progress) = &record.at_bats[0];
+        assert_eq!(hit_by_pitch.outcome.outcome_type, AtBatOutcomeType::HitByPitch);
+        assert_eq!(hit_by_pitch.pitches.len(), 1);
+        assert_eq!(progress.bases, [true, true, false]);
+    }
+
+    #[test]
+    fn wild_pitch_scores_runner_from_third() {
+        let mut decider = ScriptedDecider::new()
+            .next_flip(Draw::PitchIsBall, true)
+            .next_flip(Draw::WildPitch, true);
+
+        let record = simulate_half_inning(
+            0,
+            &Team::default(),
+            &Team::default(),
+            [Some(7), None, Some(8)],
+            None,
+            0,
+            &Rules::default(),
+            &Ballpark::default(),
+            &GameConditions::default(),
+            &mut decider,
+        );
+
+        let (strikeout, progress) = &record.at_bats[0];
+        assert_eq!(strikeout.pitches[0].0.outcome, PitchOutcome::WildPitch);
+        assert_eq!(strikeout.pitches[0].1.balls, 1);
+        assert_eq!(strikeout.outcome.outcome_type, AtBatOutcomeType::Out);
+        assert_eq!(progress.bases, [false, true, false]);
+        assert_eq!(record.outcome.runs_scored, 1);
+    }
+
+    #[test]
+    fn wild_pitch_walks_off_mid_at_bat() {
+        let mut decider = ScriptedDecider::new()
+            .next_flip(Draw::PitchIsBall, true)
+            .next_flip(Draw::WildPitch, true);
+
+        let record = simulate_half_inning(
+            0,
+            &Team::default(),
+            &Team::default(),
+            [None, None, Some(8)],
+            Some(1),
+            8,
+            &Rules::default(),
+            &Ballpark::default(),
+            &GameConditions::default(),
+            &mut decider,
+        );
+
+        assert_eq!(record.at_bats.len(), 1);
+        let (at_bat, progress) = &record.at_bats[0];
+        assert_eq!(at_bat.pitches.len(), 1);
+        assert_eq!(at_bat.outcome.outcome_type, AtBatOutcomeType::Unfinished);
+        assert_eq!(progress.outs, 0);
+        assert_eq!(record.outcome.runs_scored, 1);
+        assert!(!record.outcome.reached_at_bat_cap);
+        assert!(decider.is_exhausted());
+    }
+
+    #[test]
+    fn tracked_pitches_are_called_on_the_plate() {
+        let zone = StrikeZone::for_batter(&Player::default());
+        let mut decider = ScriptedDecider::new()
+            // Over the outside edge, called a strike
+            .next_plate_location(-zone.half_width, 0.0)
+            .next_flip(Draw::CalledStrike, true)
+            // Just inside the top edge, called a ball
+            .next_plate_location(0.0, zone.half_height - 0.5)
+            .next_flip(Draw::CalledStrike, false)
+            // Well off the plate inside, never in doubt
+            .next_plate_location(zone.half_width + 6.0, 0.0);
+
+        let record = at_bat(&mut decider, &[None, None, None]);
+        let pitches: Vec<(PitchOutcome, PitchHeight, PitchWidth)> = record
+            .pitches
+            .iter()
+            .take(3)
+            .map(|(pitch, _)| {
+                let location = pitch.location.clone();
+                (pitch.outcome.clone(), location.height, location.width)
+            })
+            .collect();
+
+        assert_eq!(
+            pitches,
+            [
+                (PitchOutcome::Strike(false), PitchHeight::Middle, PitchWidth::Left),
+                (PitchOutcome::Ball, PitchHeight::High, PitchWidth::Center),
+                (PitchOutcome::Ball, PitchHeight::Middle, PitchWidth::Right),
+            ]
+        );
+        assert!(record.pitches[3].0.location.plate.is_none());
+    }
+
+    #[test]
+    fn taller_batters_have_taller_zones() {
+        let short = StrikeZone::for_batter(&Player {
+            height_inches: 66,
+            ..Default::default()
+        });
+        let tall = StrikeZone::for_batter(&Player {
+            height_inches: 78,
+            ..Default::default()
+        });
+        let letters_high = PlateLocation {
+            x: 0.0,
+            z: short.half_height + 0.5,
+        };
+
+        assert!(!short.contains(&letters_high));
+        assert!(tall.contains(&letters_high));
+        assert_eq!(short.half_width, tall.half_width);
+    }
+
+    #[test]
+    fn sacrifice_bunt_moves_runner_to_second() {
+        let mut decider = ScriptedDecider::new()
+            .next_batting_call(BattingCall::SacrificeBunt)
+            .next_flip(Draw::BuntContact, true);
+        let record = at_bat(&mut decider, &[Some(5), None, None]);
+        let fielding_record = fielding_record(&record);
+        let outcome = &fielding_record.base_running_record.outcome;
+
+        assert_eq!(record.pitches[0].0.outcome, PitchOutcome::Bunt);
+        assert_eq!(outcome.batter_hit_type, HitType::Sacrifice);
+        assert_eq!(outcome.outs_made, 1);
+        assert_eq!(outcome.ending_base_state, [None, Some(5), None]);
+    }
+
+    #[test]
+    fn bunt_on_the_last_out_is_not_a_sacrifice() {
+        let rules = Rules {
+            outs_per_half_inning: 1,
+            ..Default::default()
+        };
+        let mut decider = ScriptedDecider::new()
+            .next_batting_call(BattingCall::SacrificeBunt)
+            .next_flip(Draw::BuntContact, true);
+        let record = at_bat_with(
+            &AtBatSetup {
+                rules,
+                ..Default::default()
+            },
+            &mut decider,
+            &[Some(5), None, None],
+        );
+        let outcome = &fielding_record(&record).base_running_record.outcome;
+
+        assert_eq!(outcome.batter_hit_type, HitType::Out);
+        assert_eq!(outcome.outs_made, 1);
+    }
+
+    #[test]
+    fn foul_bunt_with_two_strikes_is_a_strikeout() {
+        let mut decider = ScriptedDecider::new()
+            .next_batting_call(BattingCall::SwingAway)
+            .next_batting_call(BattingCall::SwingAway)
+            .next_batting_call(BattingCall::SacrificeBunt)
+            .next_flip(Draw::BuntContact, true)
+            .next_flip(Draw::BuntFoul, true);
+        let record = at_bat(&mut decider, &[Some(5), None, None]);
+
+        assert_eq!(record.outcome.outcome_type, AtBatOutcomeType::Out);
+        assert_eq!(record.pitches.len(), 3);
+        assert_eq!(record.pitches[2].0.outcome, PitchOutcome::Foul);
+        assert!(decider.is_exhausted());
+    }
+
+    #[test]
+    fn weak_hitters_sacrifice_runners_over() {
+        let mut batting_team = Team::default();
+        batting_team.fielders[Fielder::Pitcher as usize].hitter_contact_on_strike_bias = 60;
+        batting_team.fielders[Fielder::Pitcher as usize].hitter_hit_speed_bias = -60;
+        let situation = |batter_index, outs, base_state| Situation {
+            batting_team: &batting_team,
+            fielding_team: &batting_team,
+            batter_index,
+            players_per_lineup: Rules::default().players_per_lineup,
+            outs_per_half_inning: Rules::default().outs_per_half_inning,
+            innings: Rules::default().innings,
+            count: AtBatProgress::default(),
+            outs,
+            base_state,
+            inning_index: 0,
+        };
+
+        // The pitcher bats second in the default lineup
+        assert_eq!(
+            default_batting_call(&situation(1, 0, [Some(0), None, None])),
+            BattingCall::SacrificeBunt
+        );
+        assert_eq!(
+            default_batting_call(&situation(1, 2, [Some(0), None, None])),
+            BattingCall::SwingAway
+        );
+        assert_eq!(
+            default_batting_call(&situation(0, 0, [Some(8), None, None])),
+            BattingCall::SwingAway
+        );
+    }
+
+    #[test]
+    fn intentional_walk_forces_runners_without_a_pitch() {
+        let mut decider = ScriptedDecider::new().next_fielding_call(FieldingCall::IntentionalWalk);
+
+        let record = simulate_half_inning(
+            0,
+            &Team::default(),
+            &Team::default(),
+            [Some(7), Some(8), None],
+            None,
+            0,
+            &Rules::default(),
+            &Ballpark::default(),
+            &GameConditions::default(),
+            &mut decider,
+        );
+
+        let (walk, progress) = &record.at_bats[0];
+        assert_eq!(walk.outcome.outcome_type, AtBatOutcomeType::IntentionalWalk);
+        assert!(walk.pitches.is_empty());
+        assert_eq!(progress.bases, [true, true, true]);
+    }
+
+    #[test]
+    fn intentional_walk_can_be_called_mid_at_bat() {
+        let mut decider = ScriptedDecider::new()
+            .next_fielding_call(FieldingCall::PitchToBatter)
+            .next_fielding_call(FieldingCall::PitchToBatter)
+            .next_fielding_call(FieldingCall::IntentionalWalk);
+        let record = at_bat(&mut decider, &[None, Some(3), None]);
+
+        assert_eq!(record.outcome.outcome_type, AtBatOutcomeType::IntentionalWalk);
+        assert_eq!(record.pitches.len(), 2);
+        assert!(decider.is_exhausted());
+    }
+
+    #[test]
+    fn dangerous_hitters_are_walked_late_with_first_base_open() {
+        let mut batting_team = Team::default();
+        batting_team.fielders[Fielder::Catcher as usize].hitter_contact_on_strike_bias = -80;
+        batting_team.fielders[Fielder::Catcher as usize].hitter_hit_speed_bias = 80;
+        let situation = |outs, base_state, inning_index| Situation {
+            batting_team: &batting_team,
+            fielding_team: &batting_team,
+            batter_index: 0,
+            players_per_lineup: Rules::default().players_per_lineup,
+            outs_per_half_inning: Rules::default().outs_per_half_inning,
+            innings: Rules::default().innings,
+            count: AtBatProgress::default(),
+            outs,
+            base_state,
+            inning_index,
+        };
+
+        assert_eq!(
+            default_fielding_call(&situation(1, [None, Some(4), None], 8)),
+            FieldingCall::IntentionalWalk
+        );
+        assert_eq!(
+            default_fielding_call(&situation(1, [None, Some(4), None], 2)),
+            FieldingCall::PitchAround
+        );
+        assert_eq!(
+            default_fielding_call(&situation(1, [Some(4), None, None], 8)),
+            FieldingCall::PitchToBatter
+        );
+    }
+
+    #[test]
+    fn late_innings_and_last_outs_follow_the_rules() {
+        let mut batting_team = Team::default();
+        batting_team.fielders[Fielder::Catcher as usize].hitter_contact_on_strike_bias = -80;
+        batting_team.fielders[Fielder::Catcher as usize].hitter_hit_speed_bias = 80;
+        batting_team.fielders[Fielder::Pitcher as usize].hitter_contact_on_strike_bias = 60;
+        batting_team.fielders[Fielder::Pitcher as usize].hitter_hit_speed_bias = -60;
+        let situation = |batter_index, outs, base_state, inning_index| Situation {
+            batting_team: &batting_team,
+            fielding_team: &batting_team,
+            batter_index,
+            players_per_lineup: Rules::default().players_per_lineup,
+            outs_per_half_inning: 4,
+            innings: 7,
+            count: AtBatProgress::default(),
+            outs,
+            base_state,
+            inning_index,
+        };
+
+        // The sixth inning is late in a seven inning game
+        assert_eq!(
+            default_fielding_call(&situation(0, 1, [None, Some(4), None], 5)),
+            FieldingCall::IntentionalWalk
+        );
+        assert_eq!(
+            default_fielding_call(&situation(0, 1, [None, Some(4), None], 4)),
+            FieldingCall::PitchAround
+        );
+        assert_eq!(
+            default_defensive_alignment(&situation(0, 2, [None, None, Some(4)], 5)),
+            DefensiveAlignment::InfieldIn
+        );
+        // Two outs still leaves two more to play for
+        assert_eq!(
+            default_batting_call(&situation(1, 2, [Some(0), None, None], 0)),
+            BattingCall::SacrificeBunt
+        );
+        assert_eq!(
+            default_batting_call(&situation(1, 3, [Some(0), None, None], 0)),
+            BattingCall::SwingAway
+        );
+    }
+
+    #[test]
+    fn on_deck_wraps_at_the_rules_lineup() {
+        let team = Team::default();
+        let situation = Situation {
+            batting_team: &team,
+            fielding_team: &team,
+            batter_index: 2,
+            players_per_lineup: 3,
+            outs_per_half_inning: Rules::default().outs_per_half_inning,
+            innings: Rules::default().innings,
+            count: AtBatProgress::default(),
+            outs: 0,
+            base_state: [None, None, None],
+            inning_index: 0,
+        };
+
+        assert!(std::ptr::eq(situation.on_deck(), team.player_at_batting_index(0)));
+    }
+
+    #[test]
+    fn dropped_third_strike_is_a_strikeout_without_an_out() {
+        let mut batting_team = Team::default();
+        batting_team.fielders[Fielder::Catcher as usize].baserunner_run_speed_bias = 127;
+        let mut decider = ScriptedDecider::new()
+            .next_flip(Draw::DroppedThirdStrike, true)
+            .next_value(Draw::DroppedThirdStrikeCarom, 30.0);
+
+        let record = simulate_half_inning(
+            0,
+            &batting_team,
+            &Team::default(),
+            [None, None, None],
+            None,
+            0,
+            &Rules::default(),
+            &Ballpark::default(),
+            &GameConditions::default(),
+            &mut decider,
+        );
+
+        let (strikeout, progress) = &record.at_bats[0];
+        match &strikeout.outcome.outcome_type {
+            AtBatOutcomeType::DroppedThirdStrike(dropped) => {
+                assert!(dropped.reached_base);
+                assert_eq!(dropped.play.from, Fielder::Catcher);
+                assert_eq!(dropped.play.base, Consts::FIRST);
+            }
+            outcome => panic!("Expected a dropped third strike, found {:?}", outcome),
+        }
+        assert_eq!(strikeout.pitches.last().unwrap().1.strikes, 3);
+        assert_eq!(progress.outs, 0);
+        assert_eq!(progress.bases, [true, false, false]);
+    }
+
+    #[test]
+    fn batter_cannot_run_on_third_strike_with_first_occupied() {
+        let mut decider = ScriptedDecider::new().next_flip(Draw::DroppedThirdStrike, true);
+        let record = at_bat(&mut decider, &[Some(5), None, None]);
+
+        assert_eq!(record.outcome.outcome_type, AtBatOutcomeType::Out);
+        assert!(!decider.is_exhausted());
+    }
+
+    #[test]
+    fn batter_can_run_on_third_strike_with_first_occupied_on_the_last_out() {
+        let rules = Rules {
+            outs_per_half_inning: 1,
+            ..Default::default()
+        };
+        let mut decider = ScriptedDecider::new()
+            .next_flip(Draw::DroppedThirdStrike, true)
+            .next_value(Draw::DroppedThirdStrikeCarom, 30.0);
+        let record = at_bat_with(
+            &AtBatSetup {
+                rules,
+                ..Default::default()
+            },
+            &mut decider,
+            &[Some(5), None, None],
+        );
+
+        assert!(matches!(
+            record.outcome.outcome_type,
+            AtBatOutcomeType::DroppedThirdStrike(_)
+        ));
+    }
+
+    #[test]
+    fn catcher_interference_sends_batter_to_first() {
+        let mut decider = ScriptedDecider::new()
+            .next_flip(Draw::SwingOnStrike, true)
+            .next_flip(Draw::CatcherInterference, true);
+        let record = at_bat(&mut decider, &[None, None, None]);
+
+        assert_eq!(record.outcome.outcome_type, AtBatOutcomeType::CatcherInterference);
+        assert_eq!(record.pitches[0].0.outcome, PitchOutcome::CatcherInterference);
+    }
+
+    fn foul_ball(direction: f64, launch_angle: f64, exit_speed: f64) -> ScriptedDecider {
+        ScriptedDecider::new()
+            .next_flip(Draw::SwingOnStrike, true)
+            .next_flip(Draw::ContactOnStrikeSwing, true)
+            .next_flip(Draw::FoulOnStrikeContact, true)
+            .next_value(Draw::FoulDirection, direction)
+            .next_value(Draw::FoulLaunchAngle, launch_angle)
+            .next_value(Draw::FoulExitSpeed, exit_speed)
+    }
+
+    #[test]
+    fn foul_pop_up_is_caught_behind_the_plate() {
+        let mut decider = foul_ball(225.0, 80.0, 60.0);
+        let record = at_bat(&mut decider, &[None, None, None]);
+
+        assert_eq!(record.pitches.len(), 1);
+        assert_eq!(record.pitches[0].0.outcome, PitchOutcome::Foul);
+        match &record.outcome.outcome_type {
+            AtBatOutcomeType::Hit(hit_record) => assert!(hit_record.direction.is_foul()),
+            outcome => panic!("Expected a caught foul, found {:?}", outcome),
+        }
+        assert!(matches!(
+            fielding_record(&record).landing,
+            BallLanding::Out(Fielder::Catcher, _)
+        ));
+    }
+
+    #[test]
+    fn ball_fouled_straight_back_has_no_sidespin() {
+        let mut decider = foul_ball(225.0, 80.0, 60.0);
+        let record = at_bat(&mut decider, &[None, None, None]);
+
+        match &record.outcome.outcome_type {
+            AtBatOutcomeType::Hit(hit_record) => assert_eq!(hit_record.spin.sidespin, 0.0),
+            outcome => panic!("Expected a caught foul, found {:?}", outcome),
+        }
+    }
+
+    #[test]
+    fn foul_pop_up_lands_in_the_stands_of_a_tight_park() {
+        let ballpark = Ballpark {
+            foul_territory: FoulTerritory {
+                behind_plate: 20,
+                beside_lines: 10,
+            },
+            ..Default::default()
+        };
+        let mut decider = foul_ball(225.0, 80.0, 60.0);
+        let record = at_bat_with(
+            &AtBatSetup {
+                ballpark,
+                ..Default::default()
+            },
+            &mut decider,
+            &[None, None, None],
+        );
+
+        assert_eq!(record.pitches[0].0.outcome, PitchOutcome::Foul);
+        assert_eq!(record.pitches[0].1.strikes, 1);
+        assert_eq!(record.outcome.outcome_type, AtBatOutcomeType::Out);
+        assert!(decider.is_exhausted());
+    }
+
+    #[test]
+    fn runner_tags_up_on_deep_foul_fly() {
+        let mut decider = foul_ball(-3.0, 45.0, 115.0);
+        let record = at_bat(&mut decider, &[None, None, Some(5)]);
+
+        let fielding_record = fielding_record(&record);
+        assert!(matches!(
+            fielding_record.landing,
+            BallLanding::Out(Fielder::LeftFielder, _)
+        ));
+        assert_eq!(fielding_record.base_running_record.outcome.outs_made, 1);
+        assert_eq!(fielding_record.base_running_record.outcome.runs_scored, 1);
+    }
+
+    fn batting_team_with_leadoff(bats: Bats) -> Team {
+        let mut team = Team::default();
+        team.fielders[Fielder::Catcher as usize].bats = bats;
+        team
+    }
+
+    #[test]
+    fn left_handed_batter_sprays_the_ball_mirrored() {
+        let mut decider = ScriptedDecider::new()
+            .next_flip(Draw::SwingOnStrike, true)
+            .next_flip(Draw::ContactOnStrikeSwing, true)
+            .next_value(Draw::HitDirection, 10.0);
+        let record = at_bat_with(
+            &AtBatSetup {
+                batting_team: batting_team_with_leadoff(Bats::Left),
+                ..Default::default()
+            },
+            &mut decider,
+            &[None, None, None],
+        );
+
+        match &record.outcome.outcome_type {
+            AtBatOutcomeType::Hit(hit_record) => assert_eq!(hit_record.direction.0, 80.0),
+            outcome => panic!("Expected a ball in play, found {:?}", outcome),
+        }
+    }
+
+    #[test]
+    fn inside_is_relative_to_the_batter() {
+        let script = || {
+            ScriptedDecider::new()
+                .next_flip(Draw::PitchIsBall, true)
+                .next_pitch_location(PitchHeight::Middle, PitchWidth::Left)
+                .next_flip(Draw::HitByPitch, true)
+        };
+        let at_bat_for = |bats: Bats, decider: &mut ScriptedDecider| {
+            at_bat_with(
+                &AtBatSetup {
+                    batting_team: batting_team_with_leadoff(bats),
+                    ..Default::default()
+                },
+                decider,
+                &[None, None, None],
+            )
+        };
+
+        let mut decider = script();
+        let record = at_bat_for(Bats::Left, &mut decider);
+        assert_eq!(record.outcome.outcome_type, AtBatOutcomeType::HitByPitch);
+
+        let mut decider = script();
+        let record = at_bat_for(Bats::Right, &mut decider);
+        assert_eq!(record.pitches[0].0.outcome, PitchOutcome::Ball);
+        assert!(!decider.is_exhausted());
+    }
+
+    #[test]
+    fn platoon_advantage_goes_to_opposite_sides() {
+        let pitcher = |throws: Throws| Player {
+            throws,
+            ..Default::default()
+        };
+        let batter = |bats: Bats| Player {
+            bats,
+            ..Default::default()
+        };
+
+        assert!(!batter(Bats::Right).has_platoon_advantage(&pitcher(Throws::Right)));
+        assert!(batter(Bats::Left).has_platoon_advantage(&pitcher(Throws::Right)));
+        assert!(!batter(Bats::Left).has_platoon_advantage(&pitcher(Throws::Left)));
+        assert!(batter(Bats::Switch).has_platoon_advantage(&pitcher(Throws::Right)));
+        assert!(batter(Bats::Switch).has_platoon_advantage(&pitcher(Throws::Left)));
+    }
+
+    #[test]
+    fn hit_and_run_swings_at_a_ball() {
+        let mut decider = ScriptedDecider::new()
+            .next_batting_call(BattingCall::HitAndRun)
+            .next_flip(Draw::PitchIsBall, true);
+        let record = at_bat(&mut decider, &[Some(5), None, None]);
+
+        assert_eq!(record.pitches[0].0.outcome, PitchOutcome::Strike(true));
+        assert!(decider.is_exhausted());
+    }
+
+    #[test]
+    fn hit_and_run_with_first_open_takes_a_ball() {
+        let mut decider = ScriptedDecider::new()
+            .next_batting_call(BattingCall::HitAndRun)
+            .next_flip(Draw::PitchIsBall, true);
+        let record = at_bat(&mut decider, &[None, Some(5), None]);
+
+        assert_eq!(record.pitches[0].0.outcome, PitchOutcome::Ball);
+    }
+
+    #[test]
+    fn runner_is_doubled_off_on_a_hit_and_run_line_drive() {
+        let line_drive = |call: BattingCall| {
+            ScriptedDecider::new()
+                .next_batting_call(call)
+                .next_flip(Draw::SwingOnStrike, true)
+                .next_flip(Draw::ContactOnStrikeSwing, true)
+                .next_value(Draw::HitDirection, 57.5)
+                .next_value(Draw::HitLaunchAngle, 10.0)
+                .next_value(Draw::HitExitSpeed, 100.0)
+        };
+
+        let mut decider = line_drive(BattingCall::HitAndRun);
+        let record = at_bat(&mut decider, &[Some(5), None, None]);
+        let caught = fielding_record(&record);
+        assert!(matches!(
+            caught.landing,
+            BallLanding::Out(Fielder::SecondBase, _)
+        ));
+        assert_eq!(caught.base_running_record.outcome.outs_made, 2);
+        assert_eq!(
+            caught.base_running_record.outcome.ending_base_state,
+            [None, None, None]
+        );
+
+        let mut decider = line_drive(BattingCall::SwingAway);
+        let record = at_bat(&mut decider, &[Some(5), None, None]);
+        let outcome = &fielding_record(&record).base_running_record.outcome;
+        assert_eq!(outcome.outs_made, 1);
+        assert_eq!(outcome.ending_base_state, [Some(5), None, None]);
+    }
+
+    #[test]
+    fn runner_back_to_first_draws_no_throw_from_the_tag_at_third() {
+        let mut decider = ScriptedDecider::new()
+            .next_batting_call(BattingCall::HitAndRun)
+            .next_flip(Draw::ContactOnStrikeSwing, true)
+            .next_value(Draw::HitDirection, 80.0)
+            .next_value(Draw::HitLaunchAngle, 22.0)
+            .next_value(Draw::HitExitSpeed, 120.0)
+            .next_value(Draw::ThrowSpeed, 130.0)
+            .next_value(Draw::BaserunnerSpeed, 40.0)
+            .next_value(Draw::BaserunnerSpeed, 20.0);
+        let record = at_bat(&mut decider, &[Some(5), Some(6), None]);
+        let outcome = &fielding_record(&record).base_running_record.outcome;
+
+        // Back to first without a throw, so the right fielder throws out the
+        // runner tagging from second
+        assert_eq!(outcome.outs_made, 2);
+        assert_eq!(outcome.ending_base_state, [Some(5), None, None]);
+        assert!(decider.is_exhausted());
+    }
+
+    #[test]
+    fn runners_go_on_a_full_count_with_two_outs() {
+        let full_count = |base_state: &[Option<u8>; 3], outs| {
+            let mut at_bat = AtBatSimulation::new(
+                0,
+                &Rules::default(),
+                &Ballpark::default(),
+                &GameConditions::default(),
+                base_state,
+                outs,
+                0,
+            );
+            let mut decider = ScriptedDecider::new();
+            for is_ball in [true, true, true, false, false] {
+                decider = decider.next_flip(Draw::PitchIsBall, is_ball);
+            }
+            for _ in 0..5 {
+                at_bat.step_pitch(&Team::default(), &Team::default(), &mut decider);
+            }
+            assert_eq!(at_bat.progress(), AtBatProgress { balls: 3, strikes: 2 });
+
+            at_bat
+        };
+
+        assert!(full_count(&[None, Some(4), None], 2).runners_going(BattingCall::SwingAway));
+        assert!(full_count(&[None, None, Some(4)], 2).runners_going(BattingCall::SwingAway));
+        assert!(!full_count(&[None, Some(4), None], 1).runners_going(BattingCall::SwingAway));
+        assert!(!full_count(&[None, Some(4), None], 1).runners_going(BattingCall::HitAndRun));
+        assert!(full_count(&[Some(4), None, None], 1).runners_going(BattingCall::HitAndRun));
+    }
+
+    #[test]
+    fn fast_runners_are_sent_in_hitters_counts() {
+        let mut batting_team = Team::default();
+        for fielder in batting_team.fielders.iter_mut() {
+            fielder.baserunner_run_speed_bias = 40;
+        }
+        let situation = |balls, strikes, base_state| Situation {
+            batting_team: &batting_team,
+            fielding_team: &batting_team,
+            batter_index: 0,
+            players_per_lineup: Rules::default().players_per_lineup,
+            outs_per_half_inning: Rules::default().outs_per_half_inning,
+            innings: Rules::default().innings,
+            count: AtBatProgress { balls, strikes },
+            outs: 0,
+            base_state,
+            inning_index: 0,
+        };
+
+        assert_eq!(
+            default_batting_call(&situation(1, 0, [Some(8), None, None])),
+            BattingCall::HitAndRun
+        );
+        assert_eq!(
+            default_batting_call(&situation(0, 0, [Some(8), None, None])),
+            BattingCall::SwingAway
+        );
+        assert_eq!(
+            default_batting_call(&situation(2, 1, [Some(8), Some(7), None])),
+            BattingCall::SwingAway
+        );
+    }
+
+    #[test]
+    fn fences_are_interpolated_between_points() {
+        let ballpark = Ballpark::default();
+
+        assert_eq!(ballpark.fence_distance(HitDirection(0.0)).0, 400.0);
+        assert_eq!(ballpark.fence_distance(HitDirection(37.5)).0, 425.0);
+        assert_eq!(ballpark.fence_distance(HitDirection(90.0)).0, 400.0);
+        assert_eq!(ballpark.wall_height(HitDirection(52.0)), 8.0);
+    }
+
+    #[test]
+    fn tall_wall_keeps_a_fly_ball_in_the_park() {
+        let fly_ball = || {
+            ScriptedDecider::new()
+                .next_flip(Draw::SwingOnStrike, true)
+                .next_flip(Draw::ContactOnStrikeSwing, true)
+                .next_value(Draw::HitLaunchAngle, 25.0)
+                .next_value(Draw::HitExitSpeed, 170.0)
+        };
+        let at_bat_in = |ballpark: &Ballpark| {
+            let record = at_bat_with(
+                &AtBatSetup {
+                    ballpark: *ballpark,
+                    ..Default::default()
+                },
+                &mut fly_ball(),
+                &[None, None, None],
+            );
+            match record.outcome.outcome_type {
+                AtBatOutcomeType::Hit(hit_record) => hit_record.outcome,
+                outcome => panic!("Expected a ball in play, found {:?}", outcome),
+            }
+        };
+
+        assert_eq!(at_bat_in(&Ballpark::default()), HitOutcome::HomeRun);
+
+        let monster = Ballpark {
+            fences: [Fence {
+                distance: 430,
+                height: 60,
+            }; Ballpark::FENCE_POINTS],
+            ..Default::default()
+        };
+        match at_bat_in(&monster) {
+            HitOutcome::InPlay(record) => {
+                assert!(matches!(record.landing, BallLanding::Landed(..)))
+            }
+            outcome => panic!("Expected the ball off the wall, found {:?}", outcome),
+        }
+    }
+
+    #[test]
+    fn fielding_error_puts_everyone_on() {
+        let mut decider =
+            in_play(ScriptedDecider::new(), 25.0, 0.0, 60.0).next_flip(Draw::FieldingError, true);
+        let record = at_bat(&mut decider, &[None, Some(5), None]);
+        let fielding_record = fielding_record(&record);
+
+        assert_eq!(
+            fielding_record.error,
+            Some(FieldingError {
+                fielder: Fielder::Shortstop,
+                error_type: ErrorType::Fielding,
+            })
+        );
+
+        let outcome = &fielding_record.base_running_record.outcome;
+        assert_eq!(outcome.batter_hit_type, HitType::ReachedOnError);
+        assert_eq!(outcome.outs_made, 0);
+        assert_eq!(outcome.ending_base_state, [Some(0), None, Some(5)]);
+    }
+
+    #[test]
+    fn fielding_error_on_a_force_leaves_the_runner_safe() {
+        let mut decider =
+            in_play(ScriptedDecider::new(), 25.0, 0.0, 60.0).next_flip(Draw::FieldingError, true);
+        let record = at_bat(&mut decider, &[Some(5), None, None]);
+        let outcome = &fielding_record(&record).base_running_record.outcome;
+
+        assert_eq!(outcome.batter_hit_type, HitType::FieldersChoice);
+        assert_eq!(outcome.outs_made, 0);
+        assert_eq!(outcome.ending_base_state, [Some(0), Some(5), None]);
+    }
+
+    #[test]
+    fn throwing_error_on_a_force_lets_everyone_take_another_base() {
+        let mut decider =
+            in_play(ScriptedDecider::new(), 25.0, 0.0, 60.0).next_flip(Draw::ThrowingError, true);
+        let record = at_bat(&mut decider, &[Some(5), None, None]);
+        let outcome = &fielding_record(&record).base_running_record.outcome;
+
+        assert_eq!(outcome.batter_hit_type, HitType::FieldersChoice);
+        assert_eq!(outcome.outs_made, 0);
+        assert_eq!(outcome.ending_base_state, [None, Some(0), Some(5)]);
+    }
+
+    #[test]
+    fn dropped_fly_ball_puts_everyone_on() {
+        let mut decider =
+            in_play(ScriptedDecider::new(), 45.0, 30.0, 110.0).next_flip(Draw::FieldingError, true);
+        let record = at_bat(&mut decider, &[Some(5), None, None]);
+        let fielding_record = fielding_record(&record);
+
+        assert!(matches!(
+            fielding_record.landing,
+            BallLanding::Out(Fielder::CenterFielder, _)
+        ));
+        assert_eq!(
+            fielding_record.error,
+            Some(FieldingError {
+                fielder: Fielder::CenterFielder,
+                error_type: ErrorType::Fielding,
+            })
+        );
+
+        let outcome = &fielding_record.base_running_record.outcome;
+        assert_eq!(outcome.batter_hit_type, HitType::ReachedOnError);
+        assert_eq!(outcome.outs_made, 0);
+        assert_eq!(outcome.ending_base_state, [Some(0), Some(5), None]);
+    }
+
+    #[test]
+    fn dropped_foul_pop_up_puts_the_batter_on() {
+        let mut decider = foul_ball(225.0, 80.0, 60.0).next_flip(Draw::FieldingError, true);
+        let record = at_bat(&mut decider, &[None, None, None]);
+        let fielding_record = fielding_record(&record);
+
+        assert_eq!(
+            fielding_record.error,
+            Some(FieldingError {
+                fielder: Fielder::Catcher,
+                error_type: ErrorType::Fielding,
+            })
+        );
+        assert_eq!(
+            fielding_record.base_running_record.outcome.ending_base_state,
+            [Some(0), None, None]
+        );
+    }
+
+    #[test]
+    fn throwing_error_gives_everyone_another_base() {
+        let mut decider =
+            in_play(ScriptedDecider::new(), 25.0, 0.0, 60.0).next_flip(Draw::ThrowingError, true);
+        let record = at_bat(&mut decider, &[None, Some(5), None]);
+        let outcome = &fielding_record(&record).base_running_record.outcome;
+
+        assert_eq!(outcome.batter_hit_type, HitType::ReachedOnError);
+        assert_eq!(outcome.outs_made, 0);
+        assert_eq!(outcome.runs_scored, 1);
+        assert_eq!(outcome.unearned_runs, 1);
+        assert_eq!(outcome.ending_base_state, [None, Some(0), None]);
+    }
+
+    #[test]
+    fn clean_single_has_no_throw_to_botch() {
+        let mut decider =
+            in_play(ScriptedDecider::new(), 65.0, 0.0, 90.0).next_flip(Draw::ThrowingError, true);
+        let record = at_bat(&mut decider, &[None, None, None]);
+        let fielding_record = fielding_record(&record);
+
+        assert_eq!(fielding_record.error, None);
+        assert_eq!(
+            fielding_record.base_running_record.outcome.batter_hit_type,
+            HitType::Single
+        );
+        assert!(!decider.is_exhausted());
+    }
+
+    #[test]
+    fn runner_who_reached_on_error_scores_unearned() {
+        let decider = in_play(ScriptedDecider::new(), 25.0, 0.0, 60.0)
+            .next_flip(Draw::FieldingError, true);
+        let mut decider = in_play(decider, 45.0, 25.0, 170.0);
+        let record = simulate_half_inning(
+            0,
+            &Team::default(),
+            &Team::default(),
+            [None, None, None],
+            None,
+            0,
+            &Rules::default(),
+            &Ballpark::default(),
+            &GameConditions::default(),
+            &mut decider,
+        );
+
+        // Safe on the error, a two run home run, then three unscripted strikeouts
+        assert_eq!(record.at_bats.len(), 5);
+        assert_eq!(record.outcome.runs_scored, 2);
+        assert_eq!(record.outcome.unearned_runs, 1);
+        assert_eq!(record.outcome.errors, 1);
+        assert_eq!(record.outcome.total_hits, 1);
+    }
+
+    #[test]
+    fn catcher_interference_is_charged_as_an_error() {
+        let decider = ScriptedDecider::new()
+            .next_flip(Draw::SwingOnStrike, true)
+            .next_flip(Draw::CatcherInterference, true);
+        let mut decider = in_play(decider, 45.0, 25.0, 170.0);
+        let record = simulate_half_inning(
+            0,
+            &Team::default(),
+            &Team::default(),
+            [None, None, None],
+            None,
+            0,
+            &Rules::default(),
+            &Ballpark::default(),
+            &GameConditions::default(),
+            &mut decider,
+        );
+
+        // On by interference, a two run home run, then three unscripted strikeouts
+        assert_eq!(record.at_bats.len(), 5);
+        assert_eq!(record.outcome.runs_scored, 2);
+        assert_eq!(record.outcome.unearned_runs, 1);
+        assert_eq!(record.outcome.errors, 1);
+    }
+
+    #[test]
+    fn run_scoring_on_a_passed_ball_is_unearned() {
+        let mut decider = ScriptedDecider::new()
+            .next_flip(Draw::PitchIsBall, true)
+            .next_flip(Draw::PassedBall, true);
+        let record = simulate_half_inning(
+            0,
+            &Team::default(),
+            &Team::default(),
+            [None, None, Some(8)],
+            None,
+            0,
+            &Rules::default(),
+            &Ballpark::default(),
+            &GameConditions::default(),
+            &mut decider,
+        );
+
+        assert_eq!(record.at_bats[0].0.pitches[0].0.outcome, PitchOutcome::PassedBall);
+        assert_eq!(record.outcome.runs_scored, 1);
+        assert_eq!(record.outcome.unearned_runs, 1);
+        assert_eq!(record.outcome.errors, 0);
+    }
+
+    fn carry(spin: Spin, conditions: &GameConditions) -> FieldingEvent {
+        carry_in(&Ballpark::default(), spin, conditions)
+    }
+
+    fn carry_in(ballpark: &Ballpark, spin: Spin, conditions: &GameConditions) -> FieldingEvent {
+        // 103 mph at 28 degrees
+        Trajectory::new(
+            HitDirection(45.0),
+            LaunchAngle(28.0),
+            Speed(151.0),
+            spin,
+            3.0,
+            ballpark,
+            conditions,
+        )
+        .landing()
+    }
+
+    const BACKSPIN: Spin = Spin {
+        backspin: 2200.0,
+        sidespin: 0.0,
+    };
+
+    #[test]
+    fn drag_and_backspin_shape_a_fly_ball() {
+        let calm = GameConditions::default();
+
+        let landing = carry(BACKSPIN, &calm);
+        assert!((380.0..440.0).contains(&landing.location.distance.0));
+        assert!((5.0..6.5).contains(&landing.travel_time.0));
+
+        let no_spin = carry(Spin::default(), &calm);
+        assert!(no_spin.location.distance.0 < landing.location.distance.0);
+    }
+
+    #[test]
+    fn liner_hooked_past_the_line_is_a_foul() {
+        let mut decider = in_play(ScriptedDecider::new(), 88.0, 20.0, 100.0);
+        let record = at_bat(&mut decider, &[None, None, None]);
+
+        assert_eq!(record.pitches[0].0.outcome, PitchOutcome::Foul);
+        assert_eq!(record.pitches[0].1.strikes, 1);
+        assert_eq!(record.outcome.outcome_type, AtBatOutcomeType::Out);
+        assert!(decider.is_exhausted());
+    }
+
+    #[test]
+    fn sidespin_curves_the_ball() {
+        let hook = |sidespin| {
+            carry(
+                Spin {
+                    backspin: 2200.0,
+                    sidespin,
+                },
+                &GameConditions::default(),
+            )
+            .location
+            .direction
+            .0
+        };
+
+        assert_eq!(hook(0.0), 45.0);
+        assert!(hook(800.0) > 47.0);
+        assert!(hook(-800.0) < 43.0);
+    }
+
+    #[test]
+    fn ball_carries_further_in_a_mile_high_park() {
+        let calm = GameConditions::default();
+        let mile_high = Ballpark {
+            altitude: 5280,
+            ..Default::default()
+        };
+
+        assert!(
+            carry_in(&mile_high, BACKSPIN, &calm).location.distance.0
+                > carry(BACKSPIN, &calm).location.distance.0 + 20.0
+        );
+    }
+
+    #[test]
+    fn weather_changes_how_far_the_ball_carries() {
+        let distance =
+            |conditions: GameConditions| carry(BACKSPIN, &conditions).location.distance.0;
+        let calm = distance(GameConditions::default());
+
+        let blowing_out = GameConditions {
+            wind_speed: 15,
+            wind_direction: HitDirection(45.0),
+            ..Default::default()
+        };
+        assert!(distance(blowing_out) > calm + 20.0);
+        assert!(
+            distance(GameConditions {
+                wind_direction: HitDirection(225.0),
+                ..blowing_out
+            }) < calm - 20.0
+        );
+        assert_eq!(
+            distance(GameConditions {
+                roof: Roof::Closed,
+                ..blowing_out
+            }),
+            calm
+        );
+
+        assert!(
+            distance(GameConditions {
+                temperature: 95,
+                ..Default::default()
+            }) > calm
+        );
+        assert!(
+            distance(GameConditions {
+                humidity: 90,
+                ..Default::default()
+            }) > calm
+        );
+    }
+
+    #[test]
+    fn shift_takes_away_a_pulled_grounder() {
+        let hit_type = |alignment: DefensiveAlignment| {
+            let mut decider = in_play(ScriptedDecider::new(), 22.0, 0.0, 110.0)
+                .next_defensive_alignment(alignment.clone());
+            let record = at_bat(&mut decider, &[None, None, None]);
+            assert_eq!(record.alignment, alignment);
+
+            match record.outcome.outcome_type {
+                AtBatOutcomeType::Hit(hit_record) => hit_record.outcome.hit_type(),
+                outcome => panic!("Expected a ball in play, found {:?}", outcome),
+            }
+        };
+
+        // Through the hole between short and third
+        assert_ne!(hit_type(DefensiveAlignment::Standard), HitType::Out);
+        assert_eq!(
+            hit_type(DefensiveAlignment::PullShift { bats_left: false }),
+            HitType::Out
+        );
+    }
+
+    #[test]
+    fn pull_hitters_are_shifted_unless_the_infield_comes_in() {
+        let mut batting_team = Team::default();
+        batting_team.fielders[Fielder::Catcher as usize].hitter_hit_direction_bias = -80;
+        batting_team.fielders[Fielder::Catcher as usize].bats = Bats::Left;
+        let situation = |batter_index, outs, base_state, inning_index| Situation {
+            batting_team: &batting_team,
+            fielding_team: &batting_team,
+            batter_index,
+            players_per_lineup: Rules::default().players_per_lineup,
+            outs_per_half_inning: Rules::default().outs_per_half_inning,
+            innings: Rules::default().innings,
+            count: AtBatProgress::default(),
+            outs,
+            base_state,
+            inning_index,
+        };
+
+        assert_eq!(
+            default_defensive_alignment(&situation(0, 0, [None, None, None], 0)),
+            DefensiveAlignment::PullShift { bats_left: true }
+        );
+        assert_eq!(
+            default_defensive_alignment(&situation(0, 1, [None, None, Some(3)], 8)),
+            DefensiveAlignment::InfieldIn
+        );
+        assert_eq!(
+            default_defensive_alignment(&situation(0, 2, [None, None, Some(3)], 8)),
+            DefensiveAlignment::PullShift { bats_left: true }
+        );
+        assert_eq!(
+            default_defensive_alignment(&situation(1, 0, [None, None, None], 0)),
+            DefensiveAlignment::Standard
+        );
+    }
+}
@@ -15,6 +15,7 @@ pub struct Distance(pub f64);
 #[ts(export)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 /// Angle in degrees from left foul line to right foul line that the ball was hit.
+/// Foul balls fall outside of 0 to 90, negative on the left field side.
 pub struct HitDirection(pub f64);
 
 impl HitDirection {
@@ -22,11 +23,33 @@ impl HitDirection {
     const MAX_BIAS_ANGLE: f64 = 30.0;
 
     pub fn from_decider(decider: &mut impl Decider, hitter_bias: i8) -> Self {
-        let result = Self(decider.roll_uniform(0.0..Self::MAX_ANGLE));
+        let result = Self(decider.roll_uniform(Draw::HitDirection, 0.0..Self::MAX_ANGLE));
         let biased_result = result.0 + Self::MAX_BIAS_ANGLE * (hitter_bias as f64 / std::i8::MAX as f64);
 
         HitDirection(clamp(biased_result, 0.0..=Self::MAX_ANGLE))
     }
+
+    /// Anywhere from the right field line, around behind the plate, to the
+    /// left field line. The middle of the roll is straight back.
+    pub fn foul_from_decider(decider: &mut impl Decider) -> Self {
+        let result = decider.roll_uniform(Draw::FoulDirection, Self::MAX_ANGLE..360.0);
+
+        if result > 180.0 {
+            Self(result - 360.0)
+        } else {
+            Self(result)
+        }
+    }
+
+    /// The same direction seen from the other side of the plate, turning a
+    /// right handed batter's pull into a left handed batter's
+    pub fn mirrored(self) -> Self {
+        Self(Self::MAX_ANGLE - self.0)
+    }
+
+    pub fn is_foul(&self) -> bool {
+        !(0.0..=Self::MAX_ANGLE).contains(&self.0)
+    }
 }
 
 
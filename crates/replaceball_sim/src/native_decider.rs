@@ -0,0 +1,147 @@
+use std::{
+    collections::hash_map::RandomState,
+    hash::{BuildHasher, Hasher},
+    ops::Range,
+};
+
+use crate::prelude::*;
+
+/// The native default Decider when neither the `wasm` nor the `rand` feature
+/// is enabled. Needs no dependencies, drawing from a SplitMix64 stream seeded
+/// from the standard library's hashing entropy. Games can't be reproduced
+/// from it, for which there is `SeededDecider` behind the `rand` feature.
+pub struct NativeDecider {
+    state: u64,
+}
+
+impl NativeDecider {
+    pub fn new() -> Self {
+        Self {
+            state: RandomState::new().build_hasher().finish(),
+        }
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    fn gen_range(&mut self, range: Range<u64>) -> u64 {
+        range.start + self.next_u64() % (range.end - range.start)
+    }
+
+    /// In `[0, 1)`, from the top 53 bits
+    fn gen_unit(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+
+    /// Box-Muller, discarding the second sample
+    fn gen_normal(&mut self, average: f64, std_dev: f64) -> f64 {
+        let radius = (-2.0 * (1.0 - self.gen_unit()).ln()).sqrt();
+        let angle = std::f64::consts::TAU * self.gen_unit();
+
+        average + std_dev * radius * angle.cos()
+    }
+}
+
+impl Default for NativeDecider {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Decider for NativeDecider {
+    fn roll(&mut self, check: u64, count: u64, adjust: u64) -> bool {
+        let probability = check + adjust;
+        let roll = self.gen_range(0..count);
+
+        roll < probability
+    }
+
+    fn roll_pitch_location(
+        &mut self,
+        pitch_height_bias: i8,
+        pitch_width_bias: i8,
+    ) -> PitchLocation {
+        let zone_count = 3;
+        let zone_size = -(i8::MIN as i16);
+        let full_range = zone_count * zone_size;
+
+        let width = match (self
+            .gen_range(0..full_range as u64)
+            .saturating_add_signed(pitch_width_bias as i64) as f32
+            / zone_size as f32) as u8
+        {
+            0 => PitchWidth::Left,
+            1 => PitchWidth::Center,
+            2 | 3 => PitchWidth::Right,
+            i => unreachable!("Pitch location width: {}", i),
+        };
+
+        let height = match (self
+            .gen_range(0..full_range as u64)
+            .saturating_add_signed(pitch_height_bias as i64) as f32
+            / zone_size as f32) as u8
+        {
+            0 => PitchHeight::High,
+            1 => PitchHeight::Middle,
+            2 | 3 => PitchHeight::Low,
+            i => unreachable!("Pitch location height: {}", i),
+        };
+
+        PitchLocation {
+            width,
+            height,
+            plate: None,
+        }
+    }
+
+    fn roll_plate_location(&mut self, target: PlateLocation, spread: f64) -> Option<PlateLocation> {
+        Some(PlateLocation {
+            x: self.gen_normal(target.x, spread),
+            z: self.gen_normal(target.z, spread),
+        })
+    }
+
+    fn roll_index(&mut self, range: Range<usize>) -> usize {
+        self.gen_range(range.start as u64..range.end as u64) as usize
+    }
+
+    fn roll_stat(&mut self, _draw: Draw, stat: Stat, skill: Skill) -> f64 {
+        let sample = self.gen_normal(
+            stat.average * skill.average_multiplier + skill.average_shift,
+            stat.std_dev * skill.std_dev_multiplier,
+        );
+
+        if sample < stat.range.0 {
+            stat.range.0
+        } else if stat.range.1 < sample {
+            stat.range.1
+        } else {
+            sample
+        }
+    }
+
+    fn flip(&mut self, _draw: Draw, probability: f64, bias: i8) -> bool {
+        (self.gen_unit() + (bias as f64 / i8::MAX as f64 / 4.0)) < probability
+    }
+
+    fn roll_uniform(&mut self, _draw: Draw, range: Range<f64>) -> f64 {
+        range.start + self.gen_unit() * (range.end - range.start)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn plays_a_full_game() {
+        let game = crate::simulate_game(&mut NativeDecider::new());
+
+        assert!(game.innings.len() >= Rules::default().innings as usize);
+    }
+}
@@ -1,9 +1,124 @@
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
+use strum_macros::EnumIter;
 use ts_rs::TS;
 
 use crate::prelude::*;
 
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, EnumIter, TS)]
+#[ts(export)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum PitchType {
+    Fastball,
+    Sinker,
+    Cutter,
+    Slider,
+    Curveball,
+    Changeup,
+}
+
+impl PitchType {
+    /// The pitch as a typical pitcher throws it, with the given usage weight
+    pub fn typical(self, usage: u8) -> RepertoirePitch {
+        let pitch = RepertoirePitch {
+            pitch_type: self,
+            usage,
+            ..Default::default()
+        };
+
+        match self {
+            Self::Fastball => RepertoirePitch {
+                strike_bias: 10,
+                hit_speed_bias: -10,
+                ..pitch
+            },
+            Self::Sinker => RepertoirePitch {
+                launch_angle_bias: 40,
+                contact_bias: -10,
+                ..pitch
+            },
+            Self::Cutter => RepertoirePitch {
+                hit_speed_bias: 15,
+                foul_bias: -15,
+                ..pitch
+            },
+            Self::Slider => RepertoirePitch {
+                strike_bias: -10,
+                swing_bias: 15,
+                contact_bias: -25,
+                ..pitch
+            },
+            Self::Curveball => RepertoirePitch {
+                strike_bias: -15,
+                contact_bias: -20,
+                launch_angle_bias: 15,
+                ..pitch
+            },
+            Self::Changeup => RepertoirePitch {
+                swing_bias: 20,
+                contact_bias: -10,
+                hit_speed_bias: 20,
+                ..pitch
+            },
+        }
+    }
+}
+
+/// A pitch in a pitcher's repertoire. Its biases stack on the pitcher's own
+/// `pitch_strike_bias` and `pitcher_*` biases whenever it is thrown. Positive
+/// swing, contact and foul biases draw more of each from the batter.
+#[derive(Clone, Debug, PartialEq, Eq, TS)]
+#[ts(export)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct RepertoirePitch {
+    pub pitch_type: PitchType,
+    /// Weight of how often the pitch is thrown relative to the rest of the repertoire
+    pub usage: u8,
+
+    pub strike_bias: i8,
+    pub swing_bias: i8,
+    pub contact_bias: i8,
+    pub foul_bias: i8,
+    pub launch_angle_bias: i8,
+    pub hit_speed_bias: i8,
+}
+
+impl Default for RepertoirePitch {
+    fn default() -> Self {
+        Self {
+            pitch_type: PitchType::Fastball,
+            usage: 1,
+            strike_bias: 0,
+            swing_bias: 0,
+            contact_bias: 0,
+            foul_bias: 0,
+            launch_angle_bias: 0,
+            hit_speed_bias: 0,
+        }
+    }
+}
+
+impl RepertoirePitch {
+    /// Picks a pitch from the pitcher's repertoire by usage. A single pitch
+    /// repertoire is thrown without asking the Decider.
+    pub fn from_decider(decider: &mut impl Decider, pitcher: &Player) -> Self {
+        let total_usage: u32 = pitcher.repertoire.iter().map(|pitch| pitch.usage as u32).sum();
+        if pitcher.repertoire.len() <= 1 || total_usage == 0 {
+            return pitcher.repertoire.first().cloned().unwrap_or_default();
+        }
+
+        let mut roll = decider.roll_uniform(Draw::PitchType, 0.0..total_usage as f64);
+        for pitch in pitcher.repertoire.iter() {
+            if roll < pitch.usage as f64 {
+                return pitch.clone();
+            }
+            roll -= pitch.usage as f64;
+        }
+
+        pitcher.repertoire.last().cloned().unwrap_or_default()
+    }
+}
+
 #[derive(Clone, Debug, PartialEq, Eq, TS)]
 #[ts(export)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
@@ -12,9 +127,21 @@ pub enum PitchOutcome {
     Strike(bool),
     Ball,
     Foul,
+    /// Taken on the inside, sending the batter to first
+    HitByPitch,
+    /// A ball that gets away from the catcher through the pitcher's fault,
+    /// advancing every runner a base
+    WildPitch,
+    /// A ball that gets away from the catcher through the catcher's fault,
+    /// advancing every runner a base
+    PassedBall,
+    /// The bat catches the catcher's glove on a swing, sending the batter to first
+    CatcherInterference,
 
     /// Bool defines if the hit was on a pitched ball or not
     Hit(bool),
+    /// Put down fair on a bunt attempt
+    Bunt,
 }
 
 #[derive(PartialEq, Eq, Clone, Debug, TS)]
@@ -31,69 +158,276 @@ pub enum PitchHeight {
 #[ts(export)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[repr(u8)]
+/// Across the plate as the pitcher sees it, so `Right` is inside to a right
+/// handed batter
 pub enum PitchWidth {
     Left,
     Center,
     Right,
 }
 
+impl PitchWidth {
+    /// The side of the plate the batter stands on
+    pub fn inside(bats_left: bool) -> Self {
+        if bats_left {
+            Self::Left
+        } else {
+            Self::Right
+        }
+    }
+
+    pub fn outside(bats_left: bool) -> Self {
+        if bats_left {
+            Self::Right
+        } else {
+            Self::Left
+        }
+    }
+}
+
 #[derive(Clone, Debug, TS)]
 #[ts(export)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct PitchLocation {
     pub height: PitchHeight,
     pub width: PitchWidth,
+    /// Where exactly the pitch crossed the plate, when the Decider tracks it.
+    /// `height` and `width` are then derived from it.
+    pub plate: Option<PlateLocation>,
+}
+
+impl PitchLocation {
+    /// The 3x3 grid square the location falls in, splitting the zone into thirds
+    pub fn from_plate(plate: PlateLocation, zone: &StrikeZone) -> Self {
+        let width = if plate.x < -zone.half_width / 3.0 {
+            PitchWidth::Left
+        } else if plate.x > zone.half_width / 3.0 {
+            PitchWidth::Right
+        } else {
+            PitchWidth::Center
+        };
+        let height = if plate.z > zone.half_height / 3.0 {
+            PitchHeight::High
+        } else if plate.z < -zone.half_height / 3.0 {
+            PitchHeight::Low
+        } else {
+            PitchHeight::Middle
+        };
+
+        Self {
+            height,
+            width,
+            plate: Some(plate),
+        }
+    }
+}
+
+/// Where a pitch crosses the plate, in inches from the middle of the batter's
+/// strike zone. `x` is positive toward `PitchWidth::Right` and `z` is positive up.
+#[derive(Clone, Copy, Debug, PartialEq, TS)]
+#[ts(export)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct PlateLocation {
+    pub x: f64,
+    pub z: f64,
+}
+
+/// A batter's strike zone, in inches either side of its middle
+#[derive(Clone, Copy, Debug, PartialEq, TS)]
+#[ts(export)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct StrikeZone {
+    pub half_width: f64,
+    pub half_height: f64,
+}
+
+impl StrikeZone {
+    /// Half the plate plus the radius of a ball, as any part of the ball over the plate counts
+    const HALF_WIDTH: f64 = 8.5 + 1.45;
+    /// From the hollow below the knee to the midpoint of the torso, as a share of height
+    const HEIGHT_RATIO: f64 = 0.265;
+
+    pub fn for_batter(batter: &Player) -> Self {
+        Self {
+            half_width: Self::HALF_WIDTH,
+            half_height: batter.height_inches as f64 * Self::HEIGHT_RATIO / 2.0,
+        }
+    }
+
+    /// How far inside the zone a location is, negative when outside it
+    pub fn depth(&self, plate: &PlateLocation) -> f64 {
+        (self.half_width - plate.x.abs()).min(self.half_height - plate.z.abs())
+    }
+
+    pub fn contains(&self, plate: &PlateLocation) -> bool {
+        self.depth(plate) >= 0.0
+    }
+
+    /// Chance a taken pitch is called a strike, falling off across the edges
+    /// of the zone and certain either way away from them
+    pub fn called_strike_probability(&self, plate: &PlateLocation) -> f64 {
+        clamp(
+            0.5 + self.depth(plate) / (2.0 * levels::CALLED_STRIKE_EDGE_BAND),
+            0.0..=1.0,
+        )
+    }
+
+    /// Where the pitcher aims: over the plate for a strike, or just below the
+    /// zone on the outside corner for a ball, shaded by their location biases
+    fn target(&self, pitcher: &Player, bats_left: bool, is_ball: bool) -> PlateLocation {
+        let shade = |bias: i8, half: f64| half * 2.0 / 3.0 * bias as f64 / i8::MAX as f64;
+        let x = shade(pitcher.pitch_width_bias, self.half_width);
+        let z = -shade(pitcher.pitch_height_bias, self.half_height);
+
+        if is_ball {
+            let outside = if bats_left { 1.0 } else { -1.0 };
+            PlateLocation {
+                x: x + outside * self.half_width,
+                z: -(self.half_height + levels::BALL_TARGET_OFFSET),
+            }
+        } else {
+            PlateLocation { x, z }
+        }
+    }
 }
 
 #[derive(Clone, Debug, TS)]
 #[ts(export)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct PitchRecord {
+    pub pitch_type: PitchType,
+    pub batting_call: BattingCall,
+    pub fielding_call: FieldingCall,
     pub location: PitchLocation,
     pub outcome: PitchOutcome,
 }
 
 pub fn simulate_pitch(
     decider: &mut impl Decider,
-    batter: &Player,
-    pitcher: &Player,
+    situation: &Situation,
+    call: BattingCall,
+    fielding_call: FieldingCall,
+    runners_going: bool,
 ) -> PitchRecord {
-    let location = decider.roll_pitch_location(pitcher.pitch_height_bias, pitcher.pitch_width_bias);
+    let batter = situation.batter();
+    let pitcher = situation.fielding_team.pitcher();
+    let catcher = situation.fielding_team.player_at_position(&Fielder::Catcher);
+    let base_state = &situation.base_state;
+
+    let rates = levels::count_rates(situation.count.balls, situation.count.strikes);
+    let pitch = RepertoirePitch::from_decider(decider, pitcher);
+    let zone = StrikeZone::for_batter(batter);
+    let bats_left = batter.bats.left_against(pitcher.throws);
+    let platoon_contact_bias = if batter.has_platoon_advantage(pitcher) {
+        levels::PLATOON_CONTACT_BIAS
+    } else {
+        0
+    };
 
-    if decider.flip(*levels::BALLS_PER_PITCH, pitcher.pitch_strike_bias) {
+    // A hit and run only protects runners that are going
+    let protects_runners = call == BattingCall::HitAndRun && runners_going;
+
+    let pitch_around_bias = match fielding_call {
+        FieldingCall::PitchAround => levels::PITCH_AROUND_BALL_BIAS,
+        _ => 0,
+    };
+    let intends_ball = decider.flip(
+        Draw::PitchIsBall,
+        rates.balls_per_pitch,
+        pitcher
+            .pitch_strike_bias
+            .saturating_add(pitch.strike_bias)
+            .saturating_sub(pitch_around_bias),
+    );
+    let spread = levels::PITCH_COMMAND_SPREAD
+        * (1.0 - pitcher.pitcher_command_bias as f64 / i8::MAX as f64);
+    let (location, is_ball) =
+        match decider.roll_plate_location(zone.target(pitcher, bats_left, intends_ball), spread) {
+            Some(plate) => (PitchLocation::from_plate(plate, &zone), !zone.contains(&plate)),
+            None => {
+                let location = decider
+                    .roll_pitch_location(pitcher.pitch_height_bias, pitcher.pitch_width_bias);
+                let location = if intends_ball
+                    && location.height == PitchHeight::Middle
+                    && location.width == PitchWidth::Center
+                {
+                    // A ball can't be down the middle
+                    PitchLocation {
+                        height: PitchHeight::Low,
+                        width: PitchWidth::outside(bats_left),
+                        plate: None,
+                    }
+                } else {
+                    location
+                };
+
+                (location, intends_ball)
+            }
+        };
+
+    if is_ball {
         // Is Ball
-        let location =
-            if location.height == PitchHeight::Middle && location.width == PitchWidth::Center {
-                // A ball can't be down the middle
-                PitchLocation {
-                    height: PitchHeight::Low,
-                    width: PitchWidth::Left,
-                }
-            } else {
-                location
-            };
 
-        if decider.flip(*levels::SWINGS_PER_BALL, batter.hitter_swing_on_ball_bias.saturating_sub(pitcher.pitcher_swing_on_ball_bias)) {
+        // Bunters pull the bat back on balls, and hit and runs swing regardless
+        if !call.is_bunt()
+            && (protects_runners
+                || decider.flip(
+                    Draw::SwingOnBall,
+                    rates.swings_per_ball,
+                    batter
+                        .hitter_swing_on_ball_bias
+                        .saturating_sub(pitcher.pitcher_swing_on_ball_bias)
+                        .saturating_sub(pitch.swing_bias),
+                ))
+        {
             // Swings anyways
 
             if decider.flip(
-                *levels::CONTACTS_PER_BALL_SWING,
-                batter.hitter_contact_on_ball_bias.saturating_sub(pitcher.pitcher_contact_on_ball_bias),
+                Draw::CatcherInterference,
+                *levels::CATCHER_INTERFERENCES_PER_SWING,
+                0,
+            ) {
+                // Catches the catcher's glove
+                PitchRecord {
+                    pitch_type: pitch.pitch_type,
+                    batting_call: call,
+                    fielding_call,
+                    location,
+                    outcome: PitchOutcome::CatcherInterference,
+                }
+            } else if decider.flip(
+                Draw::ContactOnBallSwing,
+                rates.contacts_per_ball_swing,
+                batter
+                    .hitter_contact_on_ball_bias
+                    .saturating_sub(pitcher.pitcher_contact_on_ball_bias)
+                    .saturating_sub(pitch.contact_bias)
+                    .saturating_sub(platoon_contact_bias),
             ) {
                 // Makes contact
 
                 if decider.flip(
-                    *levels::FOULS_PER_BALL_CONTACT,
-                    batter.hitter_foul_on_ball_contact_bias.saturating_sub(pitcher.pitcher_foul_on_ball_contact_bias),
+                    Draw::FoulOnBallContact,
+                    rates.fouls_per_ball_contact,
+                    batter
+                        .hitter_foul_on_ball_contact_bias
+                        .saturating_sub(pitcher.pitcher_foul_on_ball_contact_bias)
+                        .saturating_sub(pitch.foul_bias),
                 ) {
                     // Fouls it off
                     PitchRecord {
+                        pitch_type: pitch.pitch_type,
+                        batting_call: call,
+                        fielding_call,
                         location,
                         outcome: PitchOutcome::Foul,
                     }
                 } else {
                     // In play
                     PitchRecord {
+                        pitch_type: pitch.pitch_type,
+                        batting_call: call,
+                        fielding_call,
                         location,
                         outcome: PitchOutcome::Hit(true),
                     }
@@ -101,44 +435,125 @@ pub fn simulate_pitch(
             } else {
                 // Swinging Strike
                 PitchRecord {
+                    pitch_type: pitch.pitch_type,
+                    batting_call: call,
+                    fielding_call,
                     location,
                     outcome: PitchOutcome::Strike(true),
                 }
             }
         } else {
             // Holds off
+            let outcome = if location
+                .plate
+                .is_some_and(|plate| umpire_calls_strike(decider, &zone, &plate))
+            {
+                PitchOutcome::Strike(false)
+            } else if location.width == PitchWidth::inside(bats_left)
+                && decider.flip(
+                    Draw::HitByPitch,
+                    *levels::HIT_BY_PITCHES_PER_INSIDE_BALL,
+                    pitcher.pitcher_control_bias,
+                ) {
+                PitchOutcome::HitByPitch
+            } else if base_state.iter().any(Option::is_some)
+                && decider.flip(
+                    Draw::WildPitch,
+                    *levels::WILD_PITCHES_PER_BALL,
+                    pitcher.pitcher_control_bias,
+                )
+            {
+                PitchOutcome::WildPitch
+            } else if base_state.iter().any(Option::is_some)
+                && decider.flip(
+                    Draw::PassedBall,
+                    *levels::PASSED_BALLS_PER_BALL,
+                    catcher.catcher_blocking_bias,
+                )
+            {
+                PitchOutcome::PassedBall
+            } else {
+                PitchOutcome::Ball
+            };
+
             PitchRecord {
+                pitch_type: pitch.pitch_type,
+                batting_call: call,
+                fielding_call,
                 location,
-                outcome: PitchOutcome::Ball,
+                outcome,
             }
         }
+    } else if call.is_bunt() {
+        // Squares around on the strike
+        PitchRecord {
+            pitch_type: pitch.pitch_type,
+            batting_call: call,
+            fielding_call,
+            location,
+            outcome: bunt_attempt(decider, batter, call),
+        }
     } else {
         // Is Strike
 
-        if decider.flip(
-            *levels::SWINGS_PER_STRIKE,
-            batter.hitter_swing_on_strike_bias.saturating_sub(pitcher.pitcher_swing_on_strike_bias),
-        ) {
+        if protects_runners
+            || decider.flip(
+                Draw::SwingOnStrike,
+                rates.swings_per_strike,
+                batter
+                    .hitter_swing_on_strike_bias
+                    .saturating_sub(pitcher.pitcher_swing_on_strike_bias)
+                    .saturating_sub(pitch.swing_bias),
+            )
+        {
             // Swung at the strike
 
             if decider.flip(
-                *levels::CONTACTS_PER_STRIKE_SWING,
-                batter.hitter_contact_on_strike_bias.saturating_sub(pitcher.pitcher_contact_on_strike_bias),
+                Draw::CatcherInterference,
+                *levels::CATCHER_INTERFERENCES_PER_SWING,
+                0,
+            ) {
+                // Catches the catcher's glove
+                PitchRecord {
+                    pitch_type: pitch.pitch_type,
+                    batting_call: call,
+                    fielding_call,
+                    location,
+                    outcome: PitchOutcome::CatcherInterference,
+                }
+            } else if decider.flip(
+                Draw::ContactOnStrikeSwing,
+                rates.contacts_per_strike_swing,
+                batter
+                    .hitter_contact_on_strike_bias
+                    .saturating_sub(pitcher.pitcher_contact_on_strike_bias)
+                    .saturating_sub(pitch.contact_bias)
+                    .saturating_sub(platoon_contact_bias),
             ) {
                 // Made contact
 
                 if decider.flip(
-                    *levels::FOULS_PER_STRIKE_CONTACT,
-                    batter.hitter_foul_on_strike_contact_bias.saturating_sub(pitcher.pitcher_foul_on_strike_contact_bias),
+                    Draw::FoulOnStrikeContact,
+                    rates.fouls_per_strike_contact,
+                    batter
+                        .hitter_foul_on_strike_contact_bias
+                        .saturating_sub(pitcher.pitcher_foul_on_strike_contact_bias)
+                        .saturating_sub(pitch.foul_bias),
                 ) {
                     // Foul ball
                     PitchRecord {
+                        pitch_type: pitch.pitch_type,
+                        batting_call: call,
+                        fielding_call,
                         location,
                         outcome: PitchOutcome::Foul,
                     }
                 } else {
                     // Hit
                     PitchRecord {
+                        pitch_type: pitch.pitch_type,
+                        batting_call: call,
+                        fielding_call,
                         location,
                         outcome: PitchOutcome::Hit(false),
                     }
@@ -146,16 +561,68 @@ pub fn simulate_pitch(
             } else {
                 // Swing and a miss
                 PitchRecord {
+                    pitch_type: pitch.pitch_type,
+                    batting_call: call,
+                    fielding_call,
                     location,
                     outcome: PitchOutcome::Strike(true),
                 }
             }
         } else {
             // Stood there
+            let outcome = if location
+                .plate
+                .is_none_or(|plate| umpire_calls_strike(decider, &zone, &plate))
+            {
+                PitchOutcome::Strike(false)
+            } else {
+                PitchOutcome::Ball
+            };
+
             PitchRecord {
+                pitch_type: pitch.pitch_type,
+                batting_call: call,
+                fielding_call,
                 location,
-                outcome: PitchOutcome::Strike(false),
+                outcome,
             }
         }
     }
 }
+
+/// Missed bunts are swinging strikes, and fouls are strikes even with two
+/// strikes, which the at bat handles
+fn bunt_attempt(decider: &mut impl Decider, batter: &Player, call: BattingCall) -> PitchOutcome {
+    let (contacts, fouls) = match call {
+        BattingCall::BuntForHit => (
+            levels::BUNT_FOR_HIT_CONTACTS_PER_BUNT,
+            levels::BUNT_FOR_HIT_FOULS_PER_BUNT_CONTACT,
+        ),
+        _ => (
+            levels::SACRIFICE_CONTACTS_PER_BUNT,
+            levels::SACRIFICE_FOULS_PER_BUNT_CONTACT,
+        ),
+    };
+
+    if !decider.flip(Draw::BuntContact, contacts, batter.hitter_bunt_bias.saturating_neg()) {
+        PitchOutcome::Strike(true)
+    } else if decider.flip(Draw::BuntFoul, fouls, batter.hitter_bunt_bias) {
+        PitchOutcome::Foul
+    } else {
+        PitchOutcome::Bunt
+    }
+}
+
+/// Only pitches taken near the edges of the zone are left to the umpire's judgement
+fn umpire_calls_strike(
+    decider: &mut impl Decider,
+    zone: &StrikeZone,
+    plate: &PlateLocation,
+) -> bool {
+    let probability = zone.called_strike_probability(plate);
+    if probability <= 0.0 || probability >= 1.0 {
+        probability >= 1.0
+    } else {
+        decider.flip(Draw::CalledStrike, probability, 0)
+    }
+}
@@ -2,14 +2,17 @@
 use serde::{Deserialize, Serialize};
 use ts_rs::TS;
 
-use crate::Fielder;
+use crate::{
+    pitch::{PitchType, RepertoirePitch},
+    Fielder,
+};
 
 #[derive(Debug, Clone, TS)]
 #[ts(export)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Team {
     pub fielders: [Player; 9],
-    pub batting_order: [Fielder; 9],
+    pub batting_order: [Fielder; Team::BATTING_ORDER_LENGTH],
 }
 
 impl Default for Team {
@@ -78,6 +81,8 @@ impl Default for Team {
 }
 
 impl Team {
+    pub const BATTING_ORDER_LENGTH: usize = 9;
+
     pub fn player_at_batting_index(&self, index: u8) -> &Player {
         &self.fielders[self.batting_order[index as usize] as usize]
     }
@@ -91,16 +96,79 @@ impl Team {
     }
 }
 
+/// The side of the plate a player bats from
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, TS)]
+#[ts(export)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Bats {
+    #[default]
+    Right,
+    Left,
+    /// Bats from whichever side is opposite the pitcher's arm
+    Switch,
+}
+
+impl Bats {
+    /// Whether the batter stands on the left side of the plate against a
+    /// pitcher throwing with the given arm
+    pub fn left_against(&self, pitcher: Throws) -> bool {
+        match self {
+            Self::Right => false,
+            Self::Left => true,
+            Self::Switch => pitcher == Throws::Right,
+        }
+    }
+}
+
+/// The arm a player throws with
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, TS)]
+#[ts(export)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Throws {
+    #[default]
+    Right,
+    Left,
+}
+
+impl Player {
+    /// Batters pick the ball up sooner from a pitcher throwing from the
+    /// opposite side, as switch hitters always do
+    pub fn has_platoon_advantage(&self, pitcher: &Player) -> bool {
+        self.bats.left_against(pitcher.throws) != (pitcher.throws == Throws::Left)
+    }
+
+    /// How this player throws the given pitch, or a plain pitch of that type
+    /// if it isn't in their repertoire
+    pub fn repertoire_pitch(&self, pitch_type: PitchType) -> RepertoirePitch {
+        self.repertoire
+            .iter()
+            .find(|pitch| pitch.pitch_type == pitch_type)
+            .cloned()
+            .unwrap_or(RepertoirePitch {
+                pitch_type,
+                ..Default::default()
+            })
+    }
+}
+
 #[derive(Clone, Debug, TS)]
 #[ts(export)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Player {
     pub name: Option<String>,
     pub jersey_number: String,
+    /// Sets the size of the strike zone when batting
+    pub height_inches: u8,
+    pub bats: Bats,
+    pub throws: Throws,
 
     pub pitch_height_bias: i8,
     pub pitch_width_bias: i8,
     pub pitch_strike_bias: i8,
+    /// Keeps pitches off batters and out of the dirt
+    pub pitcher_control_bias: i8,
+    /// Hits the spot aimed for when pitches are tracked on the plate
+    pub pitcher_command_bias: i8,
 
     pub pitcher_swing_on_ball_bias: i8,
     pub pitcher_contact_on_ball_bias: i8,
@@ -114,6 +182,9 @@ pub struct Player {
     pub pitcher_launch_angle_bias: i8,
     pub pitcher_hit_speed_bias: i8,
 
+    /// Pitches thrown and how often. Empty throws only a plain fastball.
+    pub repertoire: Vec<RepertoirePitch>,
+
     pub hitter_swing_on_ball_bias: i8,
     pub hitter_contact_on_ball_bias: i8,
     pub hitter_foul_on_ball_contact_bias: i8,
@@ -125,11 +196,19 @@ pub struct Player {
     pub hitter_hit_direction_bias: i8,
     pub hitter_launch_angle_bias: i8,
     pub hitter_hit_speed_bias: i8,
+    /// Puts bunts down, and deadens them
+    pub hitter_bunt_bias: i8,
 
     pub fielder_run_speed_bias: i8,
     pub fielder_reaction_time_bias: i8,
     pub fielder_throw_speed_bias: i8,
     pub fielder_transfer_time_bias: i8,
+    /// Fields the ball cleanly and holds on to catches
+    pub fielder_hands_bias: i8,
+    /// Keeps throws on target
+    pub fielder_throw_accuracy_bias: i8,
+    /// Keeps balls in the dirt from getting past when catching
+    pub catcher_blocking_bias: i8,
 
     pub baserunner_run_speed_bias: i8,
     pub baserunner_rounding_time_bias: i8,
@@ -142,10 +221,15 @@ impl Default for Player {
         Self {
             name: None,
             jersey_number: "0".to_string(),
+            height_inches: 72,
+            bats: Bats::Right,
+            throws: Throws::Right,
 
             pitch_height_bias: 0,
             pitch_width_bias: 0,
             pitch_strike_bias: 0,
+            pitcher_control_bias: 0,
+            pitcher_command_bias: 0,
 
             pitcher_swing_on_ball_bias: 0,
             pitcher_contact_on_ball_bias: 0,
@@ -159,6 +243,8 @@ impl Default for Player {
             pitcher_launch_angle_bias: 0,
             pitcher_hit_speed_bias: 0,
 
+            repertoire: Vec::new(),
+
             hitter_swing_on_ball_bias: 0,
             hitter_contact_on_ball_bias: 0,
             hitter_foul_on_ball_contact_bias: 0,
@@ -170,11 +256,15 @@ impl Default for Player {
             hitter_hit_direction_bias: 0,
             hitter_launch_angle_bias: 0,
             hitter_hit_speed_bias: 0,
+            hitter_bunt_bias: 0,
 
             fielder_run_speed_bias: 0,
             fielder_reaction_time_bias: 0,
             fielder_throw_speed_bias: 0,
             fielder_transfer_time_bias: 0,
+            fielder_hands_bias: 0,
+            fielder_throw_accuracy_bias: 0,
+            catcher_blocking_bias: 0,
 
             baserunner_run_speed_bias: 0,
             baserunner_rounding_time_bias: 0,
@@ -0,0 +1,511 @@
+use std::{fmt::Display, ops::Range};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::prelude::*;
+
+/// A single request the simulation made of its Decider, with its arguments
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum DeciderCall {
+    Roll {
+        check: u64,
+        count: u64,
+        adjust: u64,
+    },
+    RollPitchLocation {
+        height_bias: i8,
+        width_bias: i8,
+    },
+    RollPlateLocation {
+        target: PlateLocation,
+        spread: f64,
+    },
+    RollIndex {
+        range: Range<usize>,
+    },
+    /// The situation is summed up by who is up, the count and the outs
+    BattingCall {
+        batter_index: u8,
+        count: AtBatProgress,
+        outs: u8,
+    },
+    FieldingCall {
+        batter_index: u8,
+        count: AtBatProgress,
+        outs: u8,
+    },
+    DefensiveAlignment {
+        batter_index: u8,
+        outs: u8,
+        base_state: [Option<u8>; 3],
+    },
+    Flip {
+        draw: Draw,
+        probability: f64,
+        bias: i8,
+    },
+    RollUniform {
+        draw: Draw,
+        range: Range<f64>,
+    },
+    RollStat {
+        draw: Draw,
+        stat: Stat,
+        skill: Skill,
+    },
+}
+
+/// The answer a Decider gave to a `DeciderCall`
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum DeciderResult {
+    Bool(bool),
+    Index(usize),
+    Float(f64),
+    PitchLocation(PitchLocation),
+    PlateLocation(Option<PlateLocation>),
+    BattingCall(BattingCall),
+    FieldingCall(FieldingCall),
+    DefensiveAlignment(DefensiveAlignment),
+}
+
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct TapeEntry {
+    pub call: DeciderCall,
+    pub result: DeciderResult,
+}
+
+/// Every call made of a Decider over a simulation, in the order they were made
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct DeciderTape {
+    pub entries: Vec<TapeEntry>,
+}
+
+/// A simulated game along with the tape needed to reproduce it
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct RecordedGame {
+    pub game: GameRecord,
+    pub tape: DeciderTape,
+}
+
+/// Wraps a Decider, logging every call and result it makes to a `DeciderTape`
+pub struct RecordingDecider<D: Decider> {
+    inner: D,
+    tape: DeciderTape,
+}
+
+impl<D: Decider> RecordingDecider<D> {
+    pub fn new(inner: D) -> Self {
+        Self {
+            inner,
+            tape: DeciderTape::default(),
+        }
+    }
+
+    pub fn tape(&self) -> &DeciderTape {
+        &self.tape
+    }
+
+    pub fn into_tape(self) -> DeciderTape {
+        self.tape
+    }
+
+    pub fn into_inner(self) -> D {
+        self.inner
+    }
+
+    fn record(&mut self, call: DeciderCall, result: DeciderResult) {
+        self.tape.entries.push(TapeEntry { call, result });
+    }
+}
+
+impl<D: Decider> Decider for RecordingDecider<D> {
+    fn roll(&mut self, check: u64, count: u64, adjust: u64) -> bool {
+        let result = self.inner.roll(check, count, adjust);
+        self.record(
+            DeciderCall::Roll {
+                check,
+                count,
+                adjust,
+            },
+            DeciderResult::Bool(result),
+        );
+
+        result
+    }
+
+    fn roll_pitch_location(&mut self, height_bias: i8, width_bias: i8) -> PitchLocation {
+        let result = self.inner.roll_pitch_location(height_bias, width_bias);
+        self.record(
+            DeciderCall::RollPitchLocation {
+                height_bias,
+                width_bias,
+            },
+            DeciderResult::PitchLocation(result.clone()),
+        );
+
+        result
+    }
+
+    fn roll_plate_location(&mut self, target: PlateLocation, spread: f64) -> Option<PlateLocation> {
+        let result = self.inner.roll_plate_location(target, spread);
+        self.record(
+            DeciderCall::RollPlateLocation { target, spread },
+            DeciderResult::PlateLocation(result),
+        );
+
+        result
+    }
+
+    fn roll_index(&mut self, range: Range<usize>) -> usize {
+        let result = self.inner.roll_index(range.clone());
+        self.record(DeciderCall::RollIndex { range }, DeciderResult::Index(result));
+
+        result
+    }
+
+    fn batting_call(&mut self, situation: &Situation) -> BattingCall {
+        let result = self.inner.batting_call(situation);
+        self.record(
+            DeciderCall::BattingCall {
+                batter_index: situation.batter_index,
+                count: situation.count.clone(),
+                outs: situation.outs,
+            },
+            DeciderResult::BattingCall(result),
+        );
+
+        result
+    }
+
+    fn fielding_call(&mut self, situation: &Situation) -> FieldingCall {
+        let result = self.inner.fielding_call(situation);
+        self.record(
+            DeciderCall::FieldingCall {
+                batter_index: situation.batter_index,
+                count: situation.count.clone(),
+                outs: situation.outs,
+            },
+            DeciderResult::FieldingCall(result),
+        );
+
+        result
+    }
+
+    fn defensive_alignment(&mut self, situation: &Situation) -> DefensiveAlignment {
+        let result = self.inner.defensive_alignment(situation);
+        self.record(
+            DeciderCall::DefensiveAlignment {
+                batter_index: situation.batter_index,
+                outs: situation.outs,
+                base_state: situation.base_state,
+            },
+            DeciderResult::DefensiveAlignment(result.clone()),
+        );
+
+        result
+    }
+
+    fn flip(&mut self, draw: Draw, probability: f64, bias: i8) -> bool {
+        let result = self.inner.flip(draw, probability, bias);
+        self.record(
+            DeciderCall::Flip {
+                draw,
+                probability,
+                bias,
+            },
+            DeciderResult::Bool(result),
+        );
+
+        result
+    }
+
+    fn roll_uniform(&mut self, draw: Draw, range: Range<f64>) -> f64 {
+        let result = self.inner.roll_uniform(draw, range.clone());
+        self.record(
+            DeciderCall::RollUniform { draw, range },
+            DeciderResult::Float(result),
+        );
+
+        result
+    }
+
+    fn roll_stat(&mut self, draw: Draw, stat: Stat, skill: Skill) -> f64 {
+        let result = self.inner.roll_stat(draw, stat, skill);
+        self.record(
+            DeciderCall::RollStat { draw, stat, skill },
+            DeciderResult::Float(result),
+        );
+
+        result
+    }
+}
+
+#[derive(Clone, Debug)]
+pub enum ReplayError {
+    /// The simulation asked for more calls than the tape holds
+    Exhausted {
+        index: usize,
+        requested: Box<DeciderCall>,
+    },
+
+    /// The simulation asked for a different call than the one on the tape
+    Mismatch {
+        index: usize,
+        expected: Box<DeciderCall>,
+        requested: Box<DeciderCall>,
+    },
+
+    /// The simulation finished without using every call on the tape
+    Unused { index: usize, remaining: usize },
+}
+
+impl Display for ReplayError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Exhausted { index, requested } => write!(
+                f,
+                "Replay tape exhausted at call {}: requested {:?}",
+                index, requested
+            ),
+            Self::Mismatch {
+                index,
+                expected,
+                requested,
+            } => write!(
+                f,
+                "Replay diverged at call {}: expected {:?}, requested {:?}",
+                index, expected, requested
+            ),
+            Self::Unused { index, remaining } => write!(
+                f,
+                "Replay finished at call {} with {} unused calls on the tape",
+                index, remaining
+            ),
+        }
+    }
+}
+
+impl std::error::Error for ReplayError {}
+
+/// Plays back a `DeciderTape`, answering each call with its recorded result.
+///
+/// The Decider trait has no way to report failure, so asking for a call that
+/// differs from the tape panics with the `ReplayError` describing where the
+/// simulation diverged. Use `finish` to check that the whole tape was used.
+pub struct ReplayDecider {
+    tape: DeciderTape,
+    index: usize,
+}
+
+impl ReplayDecider {
+    pub fn new(tape: DeciderTape) -> Self {
+        Self { tape, index: 0 }
+    }
+
+    /// Index of the next call to be replayed
+    pub fn position(&self) -> usize {
+        self.index
+    }
+
+    pub fn finish(self) -> Result<(), ReplayError> {
+        let remaining = self.tape.entries.len() - self.index;
+        if remaining == 0 {
+            Ok(())
+        } else {
+            Err(ReplayError::Unused {
+                index: self.index,
+                remaining,
+            })
+        }
+    }
+
+    fn next(&mut self, requested: DeciderCall) -> DeciderResult {
+        let index = self.index;
+        let Some(entry) = self.tape.entries.get(index) else {
+            panic!(
+                "{}",
+                ReplayError::Exhausted {
+                    index,
+                    requested: Box::new(requested),
+                }
+            );
+        };
+
+        if entry.call != requested {
+            panic!(
+                "{}",
+                ReplayError::Mismatch {
+                    index,
+                    expected: Box::new(entry.call.clone()),
+                    requested: Box::new(requested),
+                }
+            );
+        }
+
+        self.index += 1;
+        entry.result.clone()
+    }
+}
+
+impl Decider for ReplayDecider {
+    fn roll(&mut self, check: u64, count: u64, adjust: u64) -> bool {
+        match self.next(DeciderCall::Roll {
+            check,
+            count,
+            adjust,
+        }) {
+            DeciderResult::Bool(result) => result,
+            result => panic!("Recorded roll has non-bool result {:?}", result),
+        }
+    }
+
+    fn roll_pitch_location(&mut self, height_bias: i8, width_bias: i8) -> PitchLocation {
+        match self.next(DeciderCall::RollPitchLocation {
+            height_bias,
+            width_bias,
+        }) {
+            DeciderResult::PitchLocation(result) => result,
+            result => panic!("Recorded pitch location has non-location result {:?}", result),
+        }
+    }
+
+    fn roll_plate_location(&mut self, target: PlateLocation, spread: f64) -> Option<PlateLocation> {
+        match self.next(DeciderCall::RollPlateLocation { target, spread }) {
+            DeciderResult::PlateLocation(result) => result,
+            result => panic!("Recorded plate location has non-location result {:?}", result),
+        }
+    }
+
+    fn roll_index(&mut self, range: Range<usize>) -> usize {
+        match self.next(DeciderCall::RollIndex { range }) {
+            DeciderResult::Index(result) => result,
+            result => panic!("Recorded index has non-index result {:?}", result),
+        }
+    }
+
+    fn batting_call(&mut self, situation: &Situation) -> BattingCall {
+        match self.next(DeciderCall::BattingCall {
+            batter_index: situation.batter_index,
+            count: situation.count.clone(),
+            outs: situation.outs,
+        }) {
+            DeciderResult::BattingCall(result) => result,
+            result => panic!("Recorded batting call has non-call result {:?}", result),
+        }
+    }
+
+    fn fielding_call(&mut self, situation: &Situation) -> FieldingCall {
+        match self.next(DeciderCall::FieldingCall {
+            batter_index: situation.batter_index,
+            count: situation.count.clone(),
+            outs: situation.outs,
+        }) {
+            DeciderResult::FieldingCall(result) => result,
+            result => panic!("Recorded fielding call has non-call result {:?}", result),
+        }
+    }
+
+    fn defensive_alignment(&mut self, situation: &Situation) -> DefensiveAlignment {
+        match self.next(DeciderCall::DefensiveAlignment {
+            batter_index: situation.batter_index,
+            outs: situation.outs,
+            base_state: situation.base_state,
+        }) {
+            DeciderResult::DefensiveAlignment(result) => result,
+            result => panic!("Recorded alignment has non-alignment result {:?}", result),
+        }
+    }
+
+    fn flip(&mut self, draw: Draw, probability: f64, bias: i8) -> bool {
+        match self.next(DeciderCall::Flip {
+            draw,
+            probability,
+            bias,
+        }) {
+            DeciderResult::Bool(result) => result,
+            result => panic!("Recorded flip has non-bool result {:?}", result),
+        }
+    }
+
+    fn roll_uniform(&mut self, draw: Draw, range: Range<f64>) -> f64 {
+        match self.next(DeciderCall::RollUniform { draw, range }) {
+            DeciderResult::Float(result) => result,
+            result => panic!("Recorded uniform roll has non-float result {:?}", result),
+        }
+    }
+
+    fn roll_stat(&mut self, draw: Draw, stat: Stat, skill: Skill) -> f64 {
+        match self.next(DeciderCall::RollStat { draw, stat, skill }) {
+            DeciderResult::Float(result) => result,
+            result => panic!("Recorded stat roll has non-float result {:?}", result),
+        }
+    }
+}
+
+#[cfg(all(test, feature = "rand"))]
+mod tests {
+    use super::*;
+    use crate::simulate_game_with_teams;
+
+    fn record_game(seed: u64, home_team: &Team, away_team: &Team) -> RecordedGame {
+        let mut decider = RecordingDecider::new(SeededDecider::new(seed));
+        let game = simulate_game_with_teams(
+            &mut decider,
+            home_team,
+            away_team,
+            &Rules::default(),
+            &Ballpark::default(),
+            &GameConditions::default(),
+        )
+        .unwrap();
+
+        RecordedGame {
+            game,
+            tape: decider.into_tape(),
+        }
+    }
+
+    #[test]
+    fn replay_reproduces_game() {
+        let recorded = record_game(7, &Team::default(), &Team::default());
+
+        let mut replay = ReplayDecider::new(recorded.tape);
+        let replayed = simulate_game_with_teams(
+            &mut replay,
+            &recorded.game.home_team,
+            &recorded.game.away_team,
+            &recorded.game.rules,
+            &recorded.game.ballpark,
+            &recorded.game.conditions,
+        )
+        .unwrap();
+
+        assert_eq!(format!("{:?}", recorded.game), format!("{:?}", replayed));
+        assert!(replay.finish().is_ok());
+    }
+
+    #[test]
+    #[should_panic(expected = "Replay diverged")]
+    fn replay_detects_divergence() {
+        let recorded = record_game(7, &Team::default(), &Team::default());
+
+        let mut home_team = Team::default();
+        home_team.fielders[Fielder::Pitcher as usize].pitch_strike_bias = 40;
+
+        simulate_game_with_teams(
+            &mut ReplayDecider::new(recorded.tape),
+            &home_team,
+            &Team::default(),
+            &Rules::default(),
+            &Ballpark::default(),
+            &GameConditions::default(),
+        )
+        .unwrap();
+    }
+}
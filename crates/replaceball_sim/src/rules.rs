@@ -0,0 +1,145 @@
+use std::fmt::Display;
+
+#[cfg(feature = "serde")]
+use serde::{de::Error, Deserialize, Deserializer, Serialize};
+use ts_rs::TS;
+
+use crate::{Score, Team};
+
+/// The rule set a game is played under. `Default` is a regulation nine inning game.
+///
+/// Deserializing checks the rules with `validate`, as does `GameSimulation::new`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, TS)]
+#[ts(export)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct Rules {
+    pub innings: u8,
+    pub outs_per_half_inning: u8,
+
+    pub balls_per_walk: u8,
+    pub strikes_per_strikeout: u8,
+
+    /// Batters cycled through before returning to the top of the order, at most
+    /// the length of a `Team`'s batting order
+    pub players_per_lineup: u8,
+
+    /// Start each extra half inning with the batter who made the previous
+    /// inning's last out placed on second
+    pub extra_innings_runner: bool,
+
+    pub mercy_rule: Option<MercyRule>,
+
+    /// Innings after which a game still tied is recorded as a tie
+    pub max_innings: Option<u8>,
+
+    /// Safety valve ending a runaway half inning, and with it the game
+    pub max_at_bats_per_half_inning: u8,
+}
+
+/// A rule set the simulation can't be played under
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RulesError {
+    ZeroInnings,
+    ZeroOutsPerHalfInning,
+    ZeroBallsPerWalk,
+    ZeroStrikesPerStrikeout,
+    ZeroPlayersPerLineup,
+    LineupLongerThanBattingOrder,
+    ZeroMaxAtBatsPerHalfInning,
+}
+
+impl Display for RulesError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let field = match self {
+            Self::ZeroInnings => "innings",
+            Self::ZeroOutsPerHalfInning => "outs_per_half_inning",
+            Self::ZeroBallsPerWalk => "balls_per_walk",
+            Self::ZeroStrikesPerStrikeout => "strikes_per_strikeout",
+            Self::ZeroPlayersPerLineup => "players_per_lineup",
+            Self::LineupLongerThanBattingOrder => {
+                return write!(
+                    f,
+                    "Rules players_per_lineup can be at most the {} batters of a batting order",
+                    Team::BATTING_ORDER_LENGTH
+                );
+            }
+            Self::ZeroMaxAtBatsPerHalfInning => "max_at_bats_per_half_inning",
+        };
+        write!(f, "Rules must have a non-zero {}", field)
+    }
+}
+
+impl std::error::Error for RulesError {}
+
+impl Rules {
+    pub fn validate(&self) -> Result<(), RulesError> {
+        if self.innings == 0 {
+            Err(RulesError::ZeroInnings)
+        } else if self.outs_per_half_inning == 0 {
+            Err(RulesError::ZeroOutsPerHalfInning)
+        } else if self.balls_per_walk == 0 {
+            Err(RulesError::ZeroBallsPerWalk)
+        } else if self.strikes_per_strikeout == 0 {
+            Err(RulesError::ZeroStrikesPerStrikeout)
+        } else if self.players_per_lineup == 0 {
+            Err(RulesError::ZeroPlayersPerLineup)
+        } else if self.players_per_lineup as usize > Team::BATTING_ORDER_LENGTH {
+            Err(RulesError::LineupLongerThanBattingOrder)
+        } else if self.max_at_bats_per_half_inning == 0 {
+            Err(RulesError::ZeroMaxAtBatsPerHalfInning)
+        } else {
+            Ok(())
+        }
+    }
+}
+
+/// Mirrors `Rules` so deserializing can be followed by `validate`
+#[cfg(feature = "serde")]
+#[derive(Deserialize)]
+#[serde(remote = "Rules")]
+struct UncheckedRules {
+    innings: u8,
+    outs_per_half_inning: u8,
+    balls_per_walk: u8,
+    strikes_per_strikeout: u8,
+    players_per_lineup: u8,
+    extra_innings_runner: bool,
+    mercy_rule: Option<MercyRule>,
+    max_innings: Option<u8>,
+    max_at_bats_per_half_inning: u8,
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for Rules {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let rules = UncheckedRules::deserialize(deserializer)?;
+        rules.validate().map_err(D::Error::custom)?;
+        Ok(rules)
+    }
+}
+
+/// Ends the game once a team leads by `runs` at the end of an inning, from
+/// the end of inning `after_innings` on
+#[derive(Debug, Clone, Copy, PartialEq, Eq, TS)]
+#[ts(export)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct MercyRule {
+    pub runs: Score,
+    pub after_innings: u8,
+}
+
+impl Default for Rules {
+    fn default() -> Self {
+        Self {
+            innings: 9,
+            outs_per_half_inning: 3,
+            balls_per_walk: 4,
+            strikes_per_strikeout: 3,
+            players_per_lineup: 9,
+            extra_innings_runner: false,
+            mercy_rule: None,
+            max_innings: None,
+            max_at_bats_per_half_inning: 27,
+        }
+    }
+}
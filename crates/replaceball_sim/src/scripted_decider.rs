@@ -0,0 +1,172 @@
+use std::{
+    collections::{HashMap, VecDeque},
+    ops::Range,
+};
+
+use crate::prelude::*;
+
+/// A Decider whose answers are scripted ahead of time, one queue per call kind.
+///
+/// Flips, uniform rolls and stat rolls are queued per `Draw` so a scenario can
+/// pin down a single draw (e.g. the launch angle) without scripting every
+/// other roll around it. Any call without a scripted answer falls back to a
+/// neutral default: flips and rolls fail, stats land on their (skill adjusted)
+/// average, uniform rolls land in the middle of their range and pitches are
+/// thrown down the middle. Plate locations are only tracked while scripted,
+/// leaving unscripted pitches on the 3x3 grid, and unscripted batting and
+/// fielding calls and alignments are left to the default strategy.
+#[derive(Default)]
+pub struct ScriptedDecider {
+    rolls: VecDeque<bool>,
+    pitch_locations: VecDeque<PitchLocation>,
+    plate_locations: VecDeque<PlateLocation>,
+    indices: VecDeque<usize>,
+    batting_calls: VecDeque<BattingCall>,
+    fielding_calls: VecDeque<FieldingCall>,
+    alignments: VecDeque<DefensiveAlignment>,
+    flips: HashMap<Draw, VecDeque<bool>>,
+    values: HashMap<Draw, VecDeque<f64>>,
+}
+
+impl ScriptedDecider {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn next_roll(mut self, result: bool) -> Self {
+        self.rolls.push_back(result);
+        self
+    }
+
+    pub fn next_pitch_location(mut self, height: PitchHeight, width: PitchWidth) -> Self {
+        self.pitch_locations.push_back(PitchLocation {
+            height,
+            width,
+            plate: None,
+        });
+        self
+    }
+
+    /// Queues where the next tracked pitch crosses the plate
+    pub fn next_plate_location(mut self, x: f64, z: f64) -> Self {
+        self.plate_locations.push_back(PlateLocation { x, z });
+        self
+    }
+
+    pub fn next_index(mut self, result: usize) -> Self {
+        self.indices.push_back(result);
+        self
+    }
+
+    /// Queues the call for the next pitch
+    pub fn next_batting_call(mut self, call: BattingCall) -> Self {
+        self.batting_calls.push_back(call);
+        self
+    }
+
+    /// Queues the fielding team's call for the next pitch
+    pub fn next_fielding_call(mut self, call: FieldingCall) -> Self {
+        self.fielding_calls.push_back(call);
+        self
+    }
+
+    /// Queues where the fielding team plays for the next at bat
+    pub fn next_defensive_alignment(mut self, alignment: DefensiveAlignment) -> Self {
+        self.alignments.push_back(alignment);
+        self
+    }
+
+    pub fn next_flip(mut self, draw: Draw, result: bool) -> Self {
+        self.flips.entry(draw).or_default().push_back(result);
+        self
+    }
+
+    /// Queues the result of a uniform or stat roll
+    pub fn next_value(mut self, draw: Draw, result: f64) -> Self {
+        self.values.entry(draw).or_default().push_back(result);
+        self
+    }
+
+    /// True once every scripted answer has been used
+    pub fn is_exhausted(&self) -> bool {
+        self.rolls.is_empty()
+            && self.pitch_locations.is_empty()
+            && self.plate_locations.is_empty()
+            && self.indices.is_empty()
+            && self.batting_calls.is_empty()
+            && self.fielding_calls.is_empty()
+            && self.alignments.is_empty()
+            && self.flips.values().all(VecDeque::is_empty)
+            && self.values.values().all(VecDeque::is_empty)
+    }
+}
+
+impl Decider for ScriptedDecider {
+    fn roll(&mut self, _check: u64, _count: u64, _adjust: u64) -> bool {
+        self.rolls.pop_front().unwrap_or(false)
+    }
+
+    fn roll_pitch_location(&mut self, _height_bias: i8, _width_bias: i8) -> PitchLocation {
+        self.pitch_locations.pop_front().unwrap_or(PitchLocation {
+            height: PitchHeight::Middle,
+            width: PitchWidth::Center,
+            plate: None,
+        })
+    }
+
+    fn roll_plate_location(
+        &mut self,
+        _target: PlateLocation,
+        _spread: f64,
+    ) -> Option<PlateLocation> {
+        self.plate_locations.pop_front()
+    }
+
+    fn roll_index(&mut self, range: Range<usize>) -> usize {
+        self.indices.pop_front().unwrap_or(range.start)
+    }
+
+    fn batting_call(&mut self, situation: &Situation) -> BattingCall {
+        self.batting_calls
+            .pop_front()
+            .unwrap_or_else(|| default_batting_call(situation))
+    }
+
+    fn fielding_call(&mut self, situation: &Situation) -> FieldingCall {
+        self.fielding_calls
+            .pop_front()
+            .unwrap_or_else(|| default_fielding_call(situation))
+    }
+
+    fn defensive_alignment(&mut self, situation: &Situation) -> DefensiveAlignment {
+        self.alignments
+            .pop_front()
+            .unwrap_or_else(|| default_defensive_alignment(situation))
+    }
+
+    fn flip(&mut self, draw: Draw, _probability: f64, _bias: i8) -> bool {
+        self.flips
+            .get_mut(&draw)
+            .and_then(VecDeque::pop_front)
+            .unwrap_or(false)
+    }
+
+    fn roll_uniform(&mut self, draw: Draw, range: Range<f64>) -> f64 {
+        self.values
+            .get_mut(&draw)
+            .and_then(VecDeque::pop_front)
+            .unwrap_or((range.start + range.end) / 2.0)
+    }
+
+    fn roll_stat(&mut self, draw: Draw, stat: Stat, skill: Skill) -> f64 {
+        self.values
+            .get_mut(&draw)
+            .and_then(VecDeque::pop_front)
+            .unwrap_or_else(|| {
+                location::clamp(
+                    stat.average * skill.average_multiplier + skill.average_shift,
+                    stat.range.0..=stat.range.1,
+                )
+            })
+    }
+}
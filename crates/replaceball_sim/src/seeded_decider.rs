@@ -0,0 +1,246 @@
+use std::ops::Range;
+
+use rand::prelude::*;
+use rand_chacha::ChaCha8Rng;
+
+use crate::prelude::*;
+
+/// A native Decider driven by a seeded ChaCha stream.
+///
+/// The same seed and teams always simulate the same `GameRecord`, on any
+/// platform, so a game can be shared or reproduced from its seed alone. Normal
+/// draws use only basic arithmetic and `sqrt` for that, rather than the
+/// platform's `ln`/`exp`.
+pub struct SeededDecider {
+    seed: u64,
+    rand: ChaCha8Rng,
+}
+
+impl SeededDecider {
+    pub fn new(seed: u64) -> Self {
+        Self {
+            seed,
+            rand: ChaCha8Rng::seed_from_u64(seed),
+        }
+    }
+
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    /// Marsaglia's polar method, discarding the second sample
+    fn sample_normal(&mut self, average: f64, std_dev: f64) -> f64 {
+        loop {
+            let u: f64 = self.rand.gen_range(-1.0..1.0);
+            let v: f64 = self.rand.gen_range(-1.0..1.0);
+            let s = u * u + v * v;
+
+            if 0.0 < s && s < 1.0 {
+                return average + std_dev * u * (-2.0 * ln(s) / s).sqrt();
+            }
+        }
+    }
+}
+
+/// Natural log of a positive, normal `x`, reproducible across platforms.
+/// Splits off the binary exponent, then sums the `atanh` series for the
+/// mantissa, which is folded into `[sqrt(1/2), sqrt(2))` so it converges fast.
+fn ln(x: f64) -> f64 {
+    let bits = x.to_bits();
+    let mut exponent = ((bits >> 52) & 0x7ff) as i64 - 1023;
+    let mut mantissa = f64::from_bits((bits & ((1 << 52) - 1)) | (1023 << 52));
+
+    if std::f64::consts::SQRT_2 <= mantissa {
+        mantissa /= 2.0;
+        exponent += 1;
+    }
+
+    let s = (mantissa - 1.0) / (mantissa + 1.0);
+    let s2 = s * s;
+    let series = (1..=12)
+        .rev()
+        .fold(0.0, |sum, k| sum * s2 + 1.0 / (2 * k - 1) as f64);
+
+    exponent as f64 * std::f64::consts::LN_2 + 2.0 * s * series
+}
+
+impl Default for SeededDecider {
+    /// Seeds from the thread's entropy source
+    fn default() -> Self {
+        Self::new(rand::random())
+    }
+}
+
+impl Decider for SeededDecider {
+    fn roll(&mut self, check: u64, count: u64, adjust: u64) -> bool {
+        let probability = check + adjust;
+        let roll = self.rand.gen_range(0..count);
+
+        roll < probability
+    }
+
+    fn roll_pitch_location(
+        &mut self,
+        pitch_height_bias: i8,
+        pitch_width_bias: i8,
+    ) -> PitchLocation {
+        let zone_count = 3;
+        let zone_size = -(i8::MIN as i16);
+        let full_range = zone_count * zone_size;
+
+        let width = match (self.rand.gen_range(0..full_range as u64)
+            .saturating_add_signed(pitch_width_bias as i64) as f32
+            / zone_size as f32) as u8
+        {
+            0 => PitchWidth::Left,
+            1 => PitchWidth::Center,
+            2 | 3 => PitchWidth::Right,
+            i => unreachable!("Pitch location width: {}", i),
+        };
+
+        let height = match (self.rand.gen_range(0..full_range as u64)
+            .saturating_add_signed(pitch_height_bias as i64) as f32
+            / zone_size as f32) as u8
+        {
+            0 => PitchHeight::High,
+            1 => PitchHeight::Middle,
+            2 | 3 => PitchHeight::Low,
+            i => unreachable!("Pitch location height: {}", i),
+        };
+
+        PitchLocation {
+            width,
+            height,
+            plate: None,
+        }
+    }
+
+    fn roll_plate_location(&mut self, target: PlateLocation, spread: f64) -> Option<PlateLocation> {
+        Some(PlateLocation {
+            x: self.sample_normal(target.x, spread),
+            z: self.sample_normal(target.z, spread),
+        })
+    }
+
+    fn roll_index(&mut self, range: Range<usize>) -> usize {
+        // Sampled as u64 so the stream does not depend on the platform's pointer width
+        self.rand.gen_range(range.start as u64..range.end as u64) as usize
+    }
+
+    fn roll_stat(&mut self, _draw: Draw, stat: Stat, skill: Skill) -> f64 {
+        let sample = self.sample_normal(
+            stat.average * skill.average_multiplier + skill.average_shift,
+            stat.std_dev * skill.std_dev_multiplier,
+        );
+
+        if sample < stat.range.0 {
+            stat.range.0
+        } else if stat.range.1 < sample {
+            stat.range.1
+        } else {
+            sample
+        }
+    }
+
+    fn flip(&mut self, _draw: Draw, probability: f64, bias: i8) -> bool {
+        (self.rand.gen_range(0.0..1.0) + (bias as f64 / i8::MAX as f64 / 4.0)) < probability
+    }
+
+    fn roll_uniform(&mut self, _draw: Draw, range: Range<f64>) -> f64 {
+        self.rand.gen_range(range)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::simulate_game_with_teams;
+
+    #[test]
+    fn same_seed_same_game() {
+        let home_team = Team::default();
+        let away_team = Team::default();
+
+        for seed in [0, 1, 42, u64::MAX] {
+            let first = simulate_game_with_teams(
+                &mut SeededDecider::new(seed),
+                &home_team,
+                &away_team,
+                &Rules::default(),
+                &Ballpark::default(),
+                &GameConditions::default(),
+            )
+            .unwrap();
+            let second = simulate_game_with_teams(
+                &mut SeededDecider::new(seed),
+                &home_team,
+                &away_team,
+                &Rules::default(),
+                &Ballpark::default(),
+                &GameConditions::default(),
+            )
+            .unwrap();
+
+            assert_eq!(format!("{:?}", first), format!("{:?}", second));
+        }
+    }
+
+    #[test]
+    fn ln_matches_std() {
+        for x in [
+            1e-300, 1e-12, 0.001, 0.25, 0.5, 0.7, 0.999_999, 1.0, 1.5, 2.0, 1e9,
+        ] {
+            let expected = f64::ln(x);
+            assert!(
+                (ln(x) - expected).abs() <= 1e-15 * expected.abs().max(1.0),
+                "ln({}) = {}, expected {}",
+                x,
+                ln(x),
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn normal_draws_are_pinned() {
+        let mut decider = SeededDecider::new(42);
+        let draws: Vec<u64> = (0..5)
+            .map(|_| decider.sample_normal(0.0, 1.0).to_bits())
+            .collect();
+
+        assert_eq!(
+            draws,
+            vec![
+                4593777358611831396,
+                13830986477247399585,
+                13825395772567733866,
+                13827074927821079689,
+                4605077012580393600,
+            ]
+        );
+    }
+
+    #[test]
+    fn different_seeds_differ() {
+        let first = simulate_game_with_teams(
+            &mut SeededDecider::new(1),
+            &Team::default(),
+            &Team::default(),
+            &Rules::default(),
+            &Ballpark::default(),
+            &GameConditions::default(),
+        )
+        .unwrap();
+        let second = simulate_game_with_teams(
+            &mut SeededDecider::new(2),
+            &Team::default(),
+            &Team::default(),
+            &Rules::default(),
+            &Ballpark::default(),
+            &GameConditions::default(),
+        )
+        .unwrap();
+
+        assert_ne!(format!("{:?}", first), format!("{:?}", second));
+    }
+}
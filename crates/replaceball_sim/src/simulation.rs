@@ -0,0 +1,458 @@
+use crate::{
+    half_inning::{HalfInningSimulation, PitchStep},
+    inning::{starting_bases, InningSituation},
+    prelude::*,
+};
+
+/// Everything a single pitch produced, along with any at bat, half inning or
+/// inning it brought to an end
+#[derive(Clone, Debug)]
+pub struct PitchEvent {
+    /// `None` when the batter was walked intentionally without a pitch
+    pub pitch: Option<PitchRecord>,
+    pub at_bat_progress: AtBatProgress,
+    pub at_bat: Option<(AtBatRecord, HalfInningProgress)>,
+    pub half_inning: Option<HalfInningRecord>,
+    pub inning: Option<(InningRecord, GameProgress)>,
+}
+
+/// A game in progress that can be driven a pitch, at bat or half inning at a
+/// time, for live viewers and interactive play.
+///
+/// Running it to the end with `finish` produces the same `GameRecord` as
+/// `simulate_game_with_teams` given the same Decider.
+pub struct GameSimulation<D: Decider> {
+    decider: D,
+    home_team: Team,
+    away_team: Team,
+    rules: Rules,
+    ballpark: Ballpark,
+    conditions: GameConditions,
+
+    innings: Vec<(InningRecord, GameProgress)>,
+    progress: GameProgress,
+    away_batting_index: u8,
+    home_batting_index: u8,
+
+    /// The finished top half, held while the bottom half is played
+    away_half: Option<HalfInningRecord>,
+    half_inning: Option<HalfInningSimulation>,
+    end_reason: Option<GameEndReason>,
+}
+
+impl<D: Decider> GameSimulation<D> {
+    /// Fails when the rules are ones the game can't be played under
+    pub fn new(
+        decider: D,
+        home_team: &Team,
+        away_team: &Team,
+        rules: &Rules,
+        ballpark: &Ballpark,
+        conditions: &GameConditions,
+    ) -> Result<Self, RulesError> {
+        rules.validate()?;
+
+        Ok(Self {
+            decider,
+            home_team: home_team.clone(),
+            away_team: away_team.clone(),
+            rules: *rules,
+            ballpark: *ballpark,
+            conditions: *conditions,
+            innings: Vec::new(),
+            progress: GameProgress::default(),
+            away_batting_index: 0,
+            home_batting_index: 0,
+            away_half: None,
+            half_inning: None,
+            end_reason: None,
+        })
+    }
+
+    /// Throws the next pitch, or returns `None` once the game is over. An
+    /// intentional walk is stepped over as an event without a pitch.
+    pub fn step_pitch(&mut self) -> Option<PitchEvent> {
+        if self.end_reason.is_some() {
+            return None;
+        }
+
+        let is_top_half = self.is_top_half();
+        let half_inning = match self.half_inning.take() {
+            Some(half_inning) => half_inning,
+            None => self.start_half_inning(),
+        };
+        let half_inning = self.half_inning.insert(half_inning);
+
+        let (batting_team, fielding_team) = if is_top_half {
+            (&self.away_team, &self.home_team)
+        } else {
+            (&self.home_team, &self.away_team)
+        };
+        let PitchStep {
+            pitch,
+            at_bat_progress,
+            finished_at_bat,
+        } = half_inning.step_pitch(batting_team, fielding_team, &mut self.decider);
+
+        let mut event = PitchEvent {
+            pitch,
+            at_bat_progress,
+            at_bat: finished_at_bat,
+            half_inning: None,
+            inning: None,
+        };
+
+        if half_inning.is_over() {
+            let next_batting_index = half_inning.batting_index();
+            let record = self
+                .half_inning
+                .take()
+                .expect("Half inning finished but is missing")
+                .into_record();
+            event.half_inning = Some(record.clone());
+            event.inning = self.finish_half_inning(record, next_batting_index);
+        }
+
+        Some(event)
+    }
+
+    /// Plays out the current (or next) at bat, or returns `None` once the game is over
+    pub fn step_at_bat(&mut self) -> Option<(AtBatRecord, HalfInningProgress)> {
+        loop {
+            if let Some(at_bat) = self.step_pitch()?.at_bat {
+                return Some(at_bat);
+            }
+        }
+    }
+
+    /// Plays out the current (or next) half inning, or returns `None` once the game is over
+    pub fn step_half_inning(&mut self) -> Option<HalfInningRecord> {
+        loop {
+            if let Some(half_inning) = self.step_pitch()?.half_inning {
+                return Some(half_inning);
+            }
+        }
+    }
+
+    /// Plays out the rest of the game
+    pub fn finish(mut self) -> GameRecord {
+        while self.step_pitch().is_some() {}
+
+        GameRecord {
+            innings: self.innings.into_boxed_slice(),
+            outcome: GameOutcome {
+                home_score: self.progress.home_score,
+                home_hits: self.progress.home_hits,
+                home_errors: self.progress.home_errors,
+                away_score: self.progress.away_score,
+                away_hits: self.progress.away_hits,
+                away_errors: self.progress.away_errors,
+                end_reason: self.end_reason.expect("Game finished without an end reason"),
+            },
+            away_team: self.away_team,
+            home_team: self.home_team,
+            rules: self.rules,
+            ballpark: self.ballpark,
+            conditions: self.conditions,
+        }
+    }
+
+    /// Score, hits and errors so far, including the half inning in progress
+    pub fn game_progress(&self) -> GameProgress {
+        let mut progress = self.progress.clone();
+        if let Some(half_inning) = &self.half_inning {
+            let (score, hits, errors) = if self.is_top_half() {
+                (&mut progress.away_score, &mut progress.away_hits, &mut progress.home_errors)
+            } else {
+                (&mut progress.home_score, &mut progress.home_hits, &mut progress.away_errors)
+            };
+            *score += half_inning.progress().score_change;
+            *hits += half_inning.total_hits() as u16;
+            *errors += half_inning.errors() as u16;
+        }
+
+        progress
+    }
+
+    /// Bases, outs and runs of the half inning in progress
+    pub fn half_inning_progress(&self) -> Option<HalfInningProgress> {
+        self.half_inning.as_ref().map(HalfInningSimulation::progress)
+    }
+
+    /// Count of the at bat in progress
+    pub fn at_bat_progress(&self) -> Option<AtBatProgress> {
+        self.half_inning
+            .as_ref()
+            .and_then(HalfInningSimulation::at_bat_progress)
+    }
+
+    /// Zero based index of the inning in progress, or up next
+    pub fn inning_index(&self) -> usize {
+        self.innings.len()
+    }
+
+    /// Lineup index of the batter at the plate, or due up next
+    pub fn batting_index(&self) -> u8 {
+        match &self.half_inning {
+            Some(half_inning) => half_inning.batting_index(),
+            None if self.is_top_half() => self.away_batting_index,
+            None => self.home_batting_index,
+        }
+    }
+
+    pub fn is_top_half(&self) -> bool {
+        self.away_half.is_none()
+    }
+
+    pub fn innings(&self) -> &[(InningRecord, GameProgress)] {
+        &self.innings
+    }
+
+    pub fn rules(&self) -> &Rules {
+        &self.rules
+    }
+
+    pub fn ballpark(&self) -> &Ballpark {
+        &self.ballpark
+    }
+
+    pub fn conditions(&self) -> &GameConditions {
+        &self.conditions
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.end_reason.is_some()
+    }
+
+    pub fn end_reason(&self) -> Option<GameEndReason> {
+        self.end_reason
+    }
+
+    pub fn decider_mut(&mut self) -> &mut D {
+        &mut self.decider
+    }
+
+    pub fn into_decider(self) -> D {
+        self.decider
+    }
+
+    /// The score going into the current inning
+    fn situation(&self) -> InningSituation {
+        let (away_score, home_score) = self
+            .innings
+            .last()
+            .map_or((0, 0), |(_, progress)| (progress.away_score, progress.home_score));
+
+        InningSituation {
+            inning_index: self.innings.len(),
+            away_score,
+            home_score,
+        }
+    }
+
+    fn start_half_inning(&self) -> HalfInningSimulation {
+        let situation = self.situation();
+        let is_extra_inning = situation.is_extra_inning(&self.rules);
+
+        match &self.away_half {
+            None => HalfInningSimulation::new(
+                self.away_batting_index,
+                starting_bases(self.away_batting_index, is_extra_inning, &self.rules),
+                None,
+                situation.inning_index,
+                &self.rules,
+                &self.ballpark,
+                &self.conditions,
+            ),
+            Some(away) => HalfInningSimulation::new(
+                self.home_batting_index,
+                starting_bases(self.home_batting_index, is_extra_inning, &self.rules),
+                situation.runs_to_win(away.outcome.runs_scored, &self.rules),
+                situation.inning_index,
+                &self.rules,
+                &self.ballpark,
+                &self.conditions,
+            ),
+        }
+    }
+
+    /// `next_batting_index` is the batter due up when the team next bats
+    fn finish_half_inning(
+        &mut self,
+        record: HalfInningRecord,
+        next_batting_index: u8,
+    ) -> Option<(InningRecord, GameProgress)> {
+        match self.away_half.take() {
+            None => {
+                self.progress.away_score += record.outcome.runs_scored;
+                self.progress.away_hits += record.outcome.total_hits as u16;
+                self.progress.home_errors += record.outcome.errors as u16;
+                self.away_batting_index = next_batting_index;
+
+                let runs_to_win = self
+                    .situation()
+                    .runs_to_win(record.outcome.runs_scored, &self.rules);
+                if runs_to_win == Some(0) {
+                    Some(self.finish_inning(record, None))
+                } else {
+                    self.away_half = Some(record);
+                    None
+                }
+            }
+            Some(away) => {
+                self.progress.home_score += record.outcome.runs_scored;
+                self.progress.home_hits += record.outcome.total_hits as u16;
+                self.progress.away_errors += record.outcome.errors as u16;
+                self.home_batting_index = next_batting_index;
+
+                Some(self.finish_inning(away, Some(record)))
+            }
+        }
+    }
+
+    fn finish_inning(
+        &mut self,
+        away: HalfInningRecord,
+        home: Option<HalfInningRecord>,
+    ) -> (InningRecord, GameProgress) {
+        let reached_at_bat_cap = away.outcome.reached_at_bat_cap
+            || home
+                .as_ref()
+                .is_some_and(|home| home.outcome.reached_at_bat_cap);
+        let GameProgress {
+            away_score,
+            home_score,
+            ..
+        } = self.progress;
+        let walked_off = home.is_some() && home_score > away_score;
+
+        let inning = InningRecord {
+            outcome: InningOutcome {
+                away: away.outcome.clone(),
+                home: home.as_ref().map(|home| home.outcome.clone()),
+            },
+            away,
+            home,
+        };
+        self.innings.push((inning.clone(), self.progress.clone()));
+
+        let innings_played = self.innings.len();
+        self.end_reason = if reached_at_bat_cap {
+            Some(GameEndReason::AtBatCap)
+        } else if innings_played >= self.rules.innings as usize && away_score != home_score {
+            Some(if walked_off {
+                GameEndReason::WalkOff
+            } else {
+                GameEndReason::Regulation
+            })
+        } else if self.rules.mercy_rule.is_some_and(|mercy| {
+            innings_played >= mercy.after_innings as usize
+                && away_score.abs_diff(home_score) >= mercy.runs
+        }) {
+            Some(GameEndReason::Mercy)
+        } else if away_score == home_score
+            && self
+                .rules
+                .max_innings
+                .is_some_and(|max_innings| innings_played >= max_innings as usize)
+        {
+            Some(GameEndReason::Tie)
+        } else {
+            None
+        };
+
+        (inning, self.progress.clone())
+    }
+}
+
+#[cfg(all(test, feature = "rand"))]
+mod tests {
+    use super::*;
+    use crate::simulate_game_with_teams;
+
+    fn simulation(seed: u64) -> GameSimulation<SeededDecider> {
+        GameSimulation::new(
+            SeededDecider::new(seed),
+            &Team::default(),
+            &Team::default(),
+            &Rules::default(),
+            &Ballpark::default(),
+            &GameConditions::default(),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn stepping_pitches_matches_full_simulation() {
+        let expected = simulate_game_with_teams(
+            &mut SeededDecider::new(3),
+            &Team::default(),
+            &Team::default(),
+            &Rules::default(),
+            &Ballpark::default(),
+            &GameConditions::default(),
+        )
+        .unwrap();
+
+        let mut simulation = simulation(3);
+        let mut pitches = 0;
+        while let Some(event) = simulation.step_pitch() {
+            pitches += event.pitch.is_some() as usize;
+            if event.at_bat.is_none() {
+                assert_eq!(simulation.at_bat_progress(), Some(event.at_bat_progress));
+            }
+        }
+        let game = simulation.finish();
+
+        let expected_pitches: usize = expected
+            .innings
+            .iter()
+            .flat_map(|(inning, _)| std::iter::once(&inning.away).chain(&inning.home))
+            .flat_map(|half_inning| half_inning.at_bats.iter())
+            .map(|(at_bat, _)| at_bat.pitches.len())
+            .sum();
+        assert_eq!(pitches, expected_pitches);
+        assert_eq!(format!("{:?}", game), format!("{:?}", expected));
+    }
+
+    #[test]
+    fn step_at_bat_exposes_progress() {
+        let mut simulation = simulation(5);
+
+        while let Some((_, progress)) = simulation.step_at_bat() {
+            assert!(simulation.at_bat_progress().is_none());
+            if let Some(current) = simulation.half_inning_progress() {
+                assert_eq!(current.outs, progress.outs);
+                assert_eq!(current.score_change, progress.score_change);
+            }
+        }
+
+        assert!(simulation.is_finished());
+        assert!(simulation.step_half_inning().is_none());
+    }
+
+    #[test]
+    fn step_half_inning_alternates_halves() {
+        let mut simulation = simulation(8);
+
+        let mut halves: usize = 0;
+        while simulation.step_half_inning().is_some() {
+            halves += 1;
+            let progress = simulation.game_progress();
+            if let Some((_, last)) = simulation.innings().last() {
+                assert!(progress.away_score >= last.away_score);
+            }
+        }
+
+        let game_progress = simulation.game_progress();
+        let game = simulation.finish();
+        let expected_halves: usize = game
+            .innings
+            .iter()
+            .map(|(inning, _)| 1 + inning.home.is_some() as usize)
+            .sum();
+        assert_eq!(halves, expected_halves);
+        assert_eq!(game_progress.home_score, game.outcome.home_score);
+        assert_eq!(game_progress.away_score, game.outcome.away_score);
+    }
+}
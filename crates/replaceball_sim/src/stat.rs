@@ -1,6 +1,10 @@
 use std::ops::Mul;
 
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
 #[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Stat {
     pub average: f64,
     pub std_dev: f64,
@@ -20,6 +24,7 @@ impl Mul<Skill> for Stat {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Skill {
     pub average_multiplier: f64,
     pub average_shift: f64,
@@ -38,7 +43,7 @@ impl Default for Skill {
 
 impl Skill {
     pub fn std_dev_bias_skill(bias: i8, stat: Stat, std_mult: f64) -> Self {
-        let bias_percent = -bias as f64 / std::i8::MIN as f64;
+        let bias_percent = -(bias as f64) / std::i8::MIN as f64;
 
         Self {
             average_multiplier: 1.0,
@@ -0,0 +1,257 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use ts_rs::TS;
+
+use crate::prelude::*;
+
+/// What a manager can see when making a call before a pitch
+#[derive(Clone, Debug)]
+pub struct Situation<'a> {
+    pub batting_team: &'a Team,
+    pub fielding_team: &'a Team,
+    pub batter_index: u8,
+    /// Batters cycled through, from `Rules::players_per_lineup`
+    pub players_per_lineup: u8,
+    /// From `Rules::outs_per_half_inning`
+    pub outs_per_half_inning: u8,
+    /// Regulation innings, from `Rules::innings`
+    pub innings: u8,
+    pub count: AtBatProgress,
+    pub outs: u8,
+    pub base_state: [Option<u8>; 3],
+    /// Zero based
+    pub inning_index: usize,
+}
+
+impl Situation<'_> {
+    pub fn batter(&self) -> &Player {
+        self.batting_team.player_at_batting_index(self.batter_index)
+    }
+
+    /// The batter due up after this one
+    pub fn on_deck(&self) -> &Player {
+        self.batting_team
+            .player_at_batting_index((self.batter_index + 1) % self.players_per_lineup)
+    }
+
+    /// Whether an out here would leave the half inning going
+    pub fn before_last_out(&self) -> bool {
+        self.outs + 1 < self.outs_per_half_inning
+    }
+
+    /// The last third of regulation, from the seventh inning on in a nine
+    /// inning game
+    pub fn is_late(&self) -> bool {
+        self.inning_index >= (self.innings - self.innings / 3) as usize
+    }
+}
+
+/// How the batter approaches a pitch
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, TS)]
+#[ts(export)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum BattingCall {
+    #[default]
+    SwingAway,
+    /// Gives up the batter to move the runners over
+    SacrificeBunt,
+    /// Drops one down a line and runs
+    BuntForHit,
+    /// Sends the runners with the pitch, the batter swinging at whatever comes
+    /// to protect them
+    HitAndRun,
+}
+
+impl BattingCall {
+    pub fn is_bunt(&self) -> bool {
+        matches!(self, Self::SacrificeBunt | Self::BuntForHit)
+    }
+}
+
+/// How the fielding team handles the batter
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, TS)]
+#[ts(export)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum FieldingCall {
+    #[default]
+    PitchToBatter,
+    /// Works the edges, happy to give up a walk
+    PitchAround,
+    /// Sends the batter to first without a pitch
+    IntentionalWalk,
+}
+
+/// Where the fielding team plays its fielders for an at bat
+#[derive(Clone, Debug, Default, PartialEq, TS)]
+#[ts(export)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum DefensiveAlignment {
+    /// Everyone at `Fielder::starting_location`
+    #[default]
+    Standard,
+    /// The infield drawn in on the grass to cut down a run at the plate
+    InfieldIn,
+    /// The outfield playing deep and the corners guarding the lines, keeping
+    /// everything in front of them
+    NoDoubles,
+    /// The infield and outfield overloaded to the batter's pull side
+    PullShift { bats_left: bool },
+    /// Where each fielder plays, indexed by `Fielder`
+    Custom([Location; 9]),
+}
+
+impl DefensiveAlignment {
+    /// Where `fielder` plays before the pitch
+    pub fn location(&self, fielder: &Fielder) -> Location {
+        let standard = fielder.starting_location();
+        let moved = |direction: f64, distance: f64| Location {
+            direction: HitDirection(direction),
+            distance: Distance(distance),
+        };
+
+        match self {
+            Self::Standard => standard,
+            Self::InfieldIn => match fielder {
+                Fielder::FirstBase | Fielder::ThirdBase => moved(standard.direction.0, 90.0),
+                Fielder::SecondBase | Fielder::Shortstop => moved(standard.direction.0, 100.0),
+                _ => standard,
+            },
+            Self::NoDoubles => match fielder {
+                Fielder::FirstBase => moved(87.0, 100.0),
+                Fielder::ThirdBase => moved(3.0, 100.0),
+                Fielder::LeftFielder => moved(15.0, 290.0),
+                Fielder::CenterFielder => moved(45.0, 300.0),
+                Fielder::RightFielder => moved(75.0, 290.0),
+                _ => standard,
+            },
+            Self::PullShift { bats_left } => {
+                // Laid out against a right handed batter pulling to the left side
+                let shifted = match fielder {
+                    Fielder::SecondBase => moved(43.0, 135.0),
+                    Fielder::Shortstop => moved(22.0, 130.0),
+                    Fielder::ThirdBase => moved(5.0, 110.0),
+                    Fielder::LeftFielder => moved(15.0, 255.0),
+                    Fielder::CenterFielder => moved(37.0, 265.0),
+                    Fielder::RightFielder => moved(60.0, 245.0),
+                    _ => return standard,
+                };
+
+                if *bats_left {
+                    Location {
+                        direction: shifted.direction.mirrored(),
+                        ..shifted
+                    }
+                } else {
+                    shifted
+                }
+            }
+            Self::Custom(locations) => locations[*fielder as usize],
+        }
+    }
+}
+
+/// Batters weak enough to be worth giving up for a base
+const SACRIFICE_HITTER_BIAS: i8 = -40;
+/// Bunters and runners good enough to try for a hit
+const BUNT_FOR_HIT_BIAS: i8 = 60;
+/// Runners on first fast enough to send on a hit and run
+const HIT_AND_RUN_RUNNER_BIAS: i8 = 40;
+
+/// Batters who pull the ball hard enough to shift against
+const PULL_HITTER_BIAS: i8 = -50;
+
+/// Batters dangerous enough to be worth putting on
+const DANGEROUS_HITTER_BIAS: i16 = 50;
+/// How much weaker the batter on deck must be to put this one on
+const ON_DECK_DROP_OFF: i16 = 40;
+
+/// Higher for better hitters. A positive contact bias makes contact less
+/// likely, unlike the hit speed bias, so it counts against the batter.
+fn hitting(player: &Player) -> i16 {
+    (player.hitter_hit_speed_bias as i16 - player.hitter_contact_on_strike_bias as i16) / 2
+}
+
+/// The built-in fielding call: with first base open and a runner in scoring
+/// position, dangerous hitters followed by a weaker one are pitched around,
+/// and walked outright late in the game with outs on the board or once the
+/// count gets to three balls.
+pub fn default_fielding_call(situation: &Situation) -> FieldingCall {
+    let bases = &situation.base_state;
+    let batter = hitting(situation.batter());
+
+    if bases[Consts::FIRST].is_some()
+        || (bases[Consts::SECOND].is_none() && bases[Consts::THIRD].is_none())
+        || batter < DANGEROUS_HITTER_BIAS
+        || batter - hitting(situation.on_deck()) < ON_DECK_DROP_OFF
+    {
+        FieldingCall::PitchToBatter
+    } else if (situation.outs > 0 && situation.is_late())
+        || situation.count.balls >= 3
+    {
+        FieldingCall::IntentionalWalk
+    } else {
+        FieldingCall::PitchAround
+    }
+}
+
+/// The built-in batting call: weak hitters sacrifice runners on first or
+/// second before the last out, fast, skilled bunters try for a hit with the
+/// bases empty, and fast runners alone on first are sent on a hit and run in
+/// the batter's counts before the last out. Nothing is put on with two strikes.
+pub fn default_batting_call(situation: &Situation) -> BattingCall {
+    if situation.count.strikes >= 2 {
+        return BattingCall::SwingAway;
+    }
+
+    let batter = situation.batter();
+    let bases = &situation.base_state;
+    if situation.before_last_out()
+        && bases[Consts::THIRD].is_none()
+        && (bases[Consts::FIRST].is_some() || bases[Consts::SECOND].is_some())
+        && hitting(batter) <= SACRIFICE_HITTER_BIAS as i16
+    {
+        BattingCall::SacrificeBunt
+    } else if bases.iter().all(Option::is_none)
+        && batter.hitter_bunt_bias >= BUNT_FOR_HIT_BIAS
+        && batter.baserunner_run_speed_bias >= BUNT_FOR_HIT_BIAS
+    {
+        BattingCall::BuntForHit
+    } else if situation.before_last_out()
+        && bases[Consts::SECOND].is_none()
+        && bases[Consts::THIRD].is_none()
+        && matches!((situation.count.balls, situation.count.strikes), (1, 0) | (2, 1))
+        && bases[Consts::FIRST].is_some_and(|runner| {
+            situation
+                .batting_team
+                .player_at_batting_index(runner)
+                .baserunner_run_speed_bias
+                >= HIT_AND_RUN_RUNNER_BIAS
+        })
+    {
+        BattingCall::HitAndRun
+    } else {
+        BattingCall::SwingAway
+    }
+}
+
+/// The built-in alignment: the infield comes in late in the game to keep a
+/// runner on third from scoring on a grounder before the last out, and
+/// dead pull hitters are shifted against otherwise.
+pub fn default_defensive_alignment(situation: &Situation) -> DefensiveAlignment {
+    let batter = situation.batter();
+
+    if situation.before_last_out()
+        && situation.base_state[Consts::THIRD].is_some()
+        && situation.is_late()
+    {
+        DefensiveAlignment::InfieldIn
+    } else if batter.hitter_hit_direction_bias <= PULL_HITTER_BIAS {
+        DefensiveAlignment::PullShift {
+            bats_left: batter
+                .bats
+                .left_against(situation.fielding_team.pitcher().throws),
+        }
+    } else {
+        DefensiveAlignment::Standard
+    }
+}
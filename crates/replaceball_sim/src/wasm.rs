@@ -0,0 +1,176 @@
+use serde::de::DeserializeOwned;
+use wasm_bindgen::prelude::*;
+
+use crate::{prelude::*, simulate_game, simulate_game_with_teams};
+
+/// A Decider backed by the JS random functions in `$lib/replaceball_deps`
+#[derive(Default)]
+pub struct ExternalDecider {}
+
+impl ExternalDecider {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+#[wasm_bindgen(module = "$lib/replaceball_deps")]
+extern "C" {
+    pub fn gen_range(start: u64, end: u64) -> u64;
+    pub fn gen_usize_range(start: usize, end: usize) -> usize;
+    pub fn gen_float_range(start: f64, end: f64) -> f64;
+    pub fn gen_normal(avg: f64, std_dist: f64) -> f64;
+}
+
+impl Decider for ExternalDecider {
+    fn roll(&mut self, check: u64, count: u64, adjust: u64) -> bool {
+        let probability = check + adjust;
+        let roll = gen_range(0, count);
+
+        roll < probability
+    }
+
+    fn roll_pitch_location(
+        &mut self,
+        pitch_height_bias: i8,
+        pitch_width_bias: i8,
+    ) -> PitchLocation {
+        let zone_count = 3;
+        let zone_size = i8::MAX as i16;
+        let full_range = zone_count * zone_size;
+
+        let width = match (gen_range(0, full_range as u64)
+            .saturating_add_signed(pitch_width_bias as i64) as f32
+            / zone_size as f32) as u8
+        {
+            0 => PitchWidth::Left,
+            1 => PitchWidth::Center,
+            2 | 3 => PitchWidth::Right,
+            i => unreachable!("Pitch location width: {}", i),
+        };
+
+        let height = match (gen_range(0, full_range as u64)
+            .saturating_add_signed(pitch_height_bias as i64) as f32
+            / zone_size as f32) as u8
+        {
+            0 => PitchHeight::High,
+            1 => PitchHeight::Middle,
+            2 | 3 => PitchHeight::Low,
+            i => unreachable!("Pitch location height: {}", i),
+        };
+
+        PitchLocation {
+            width,
+            height,
+            plate: None,
+        }
+    }
+
+    fn roll_plate_location(&mut self, target: PlateLocation, spread: f64) -> Option<PlateLocation> {
+        Some(PlateLocation {
+            x: gen_normal(target.x, spread),
+            z: gen_normal(target.z, spread),
+        })
+    }
+
+    fn roll_index(&mut self, range: std::ops::Range<usize>) -> usize {
+        gen_usize_range(range.start, range.end)
+    }
+
+    fn roll_stat(&mut self, _draw: Draw, stat: Stat, skill: Skill) -> f64 {
+        let sample = gen_normal(
+            stat.average * skill.average_multiplier + skill.average_shift,
+            stat.std_dev * skill.std_dev_multiplier,
+        );
+
+        if sample < stat.range.0 {
+            stat.range.0
+        } else if stat.range.1 < sample {
+            stat.range.1
+        } else {
+            sample
+        }
+    }
+
+    fn flip(&mut self, _draw: Draw, probability: f64, bias: i8) -> bool {
+        (gen_float_range(0.0, 1.0) + (bias as f64 / i8::MAX as f64)) < probability
+    }
+
+    fn roll_uniform(&mut self, _draw: Draw, range: std::ops::Range<f64>) -> f64 {
+        gen_float_range(range.start, range.end)
+    }
+}
+
+/// Rules, ballparks and conditions are optional from JS, falling back to the defaults when left undefined
+fn value_or_default<T: DeserializeOwned + Default>(value: JsValue) -> T {
+    if value.is_undefined() || value.is_null() {
+        T::default()
+    } else {
+        serde_wasm_bindgen::from_value(value).unwrap()
+    }
+}
+
+#[wasm_bindgen]
+pub fn wasm_simulate_game_with_teams(
+    home_team: JsValue,
+    away_team: JsValue,
+    rules: JsValue,
+    ballpark: JsValue,
+    conditions: JsValue,
+) -> JsValue {
+    let home_team: Team = serde_wasm_bindgen::from_value(home_team).unwrap();
+    let away_team: Team = serde_wasm_bindgen::from_value(away_team).unwrap();
+    let rules: Rules = value_or_default(rules);
+    let ballpark: Ballpark = value_or_default(ballpark);
+    let conditions: GameConditions = value_or_default(conditions);
+
+    let game = simulate_game_with_teams(
+        &mut ExternalDecider::new(),
+        &home_team,
+        &away_team,
+        &rules,
+        &ballpark,
+        &conditions,
+    )
+    .unwrap();
+
+    serde_wasm_bindgen::to_value(&game).unwrap()
+}
+
+#[wasm_bindgen]
+pub fn wasm_record_game_with_teams(
+    home_team: JsValue,
+    away_team: JsValue,
+    rules: JsValue,
+    ballpark: JsValue,
+    conditions: JsValue,
+) -> JsValue {
+    let home_team: Team = serde_wasm_bindgen::from_value(home_team).unwrap();
+    let away_team: Team = serde_wasm_bindgen::from_value(away_team).unwrap();
+    let rules: Rules = value_or_default(rules);
+    let ballpark: Ballpark = value_or_default(ballpark);
+    let conditions: GameConditions = value_or_default(conditions);
+
+    let mut decider = RecordingDecider::new(ExternalDecider::new());
+    let game = simulate_game_with_teams(
+        &mut decider,
+        &home_team,
+        &away_team,
+        &rules,
+        &ballpark,
+        &conditions,
+    )
+    .unwrap();
+
+    serde_wasm_bindgen::to_value(&RecordedGame {
+        game,
+        tape: decider.into_tape(),
+    })
+    .unwrap()
+}
+
+#[wasm_bindgen]
+pub fn wasm_simulate_game() -> JsValue {
+    let game = simulate_game(&mut ExternalDecider::new());
+
+    serde_wasm_bindgen::to_value(&game).unwrap()
+}